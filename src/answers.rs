@@ -0,0 +1,153 @@
+use crate::Part;
+use anyhow::{bail, Context, Result};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Accepted answers for a day's puzzle input, stored in `answers/NN.toml`:
+///
+/// ```toml
+/// part1 = 322
+/// part2 = "60,21"
+/// ```
+///
+/// Answers are compared with the `Display` output of a solution, so tuple and list answers
+/// are written as strings. A part without an entry has not been accepted yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self> {
+        let table: Table = text.parse()?;
+        let mut answers = Answers::default();
+        for (key, value) in table {
+            let answer = match value {
+                Value::String(answer) => answer,
+                Value::Integer(answer) => answer.to_string(),
+                _ => bail!("Answer {} must be a string or an integer", key),
+            };
+            match key.as_str() {
+                "part1" => answers.part1 = Some(answer),
+                "part2" => answers.part2 = Some(answer),
+                _ => bail!("Unknown key {}, expected part1 or part2", key),
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Reads the answers at `path`; a missing file means nothing was accepted yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let text =
+            fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Cannot write {}", path.display()))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    /// Compares the `actual` answer of `part` with the accepted one.
+    pub fn verify(&self, part: Part, actual: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == actual => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (key, answer) in [("part1", &self.part1), ("part2", &self.part2)] {
+            let Some(answer) = answer else { continue };
+            // Integers are written bare, anything that would not read back the same is quoted
+            match answer.parse::<i64>() {
+                Ok(number) if number.to_string() == *answer => writeln!(f, "{} = {}", key, number)?,
+                _ => writeln!(f, "{} = {}", key, Value::String(answer.clone()))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of checking one part against its accepted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Missing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_verify() {
+        let answers =
+            Answers::parse("part1 = \"3,5,0,1,5,1,5,1,0\"\npart2 = 107416870455451\n").unwrap();
+        assert_eq!(
+            Verdict::Match,
+            answers.verify(Part::One, "3,5,0,1,5,1,5,1,0")
+        );
+        assert_eq!(Verdict::Match, answers.verify(Part::Two, "107416870455451"));
+        assert_eq!(
+            Verdict::Mismatch {
+                expected: "107416870455451".to_string()
+            },
+            answers.verify(Part::Two, "0")
+        );
+
+        let answers = Answers::parse("part1 = 322").unwrap();
+        assert_eq!(Verdict::Missing, answers.verify(Part::Two, "60,21"));
+
+        assert!(Answers::parse("part3 = 1").is_err());
+        assert!(Answers::parse("part1 = [6, 1]").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let answers = Answers {
+            part1: Some("322".to_string()),
+            part2: Some("60,21".to_string()),
+        };
+        assert_eq!("part1 = 322\npart2 = \"60,21\"\n", answers.to_string());
+        assert_eq!(answers, Answers::parse(&answers.to_string()).unwrap());
+
+        let leading_zero = Answers {
+            part1: Some("007".to_string()),
+            part2: None,
+        };
+        assert_eq!(
+            leading_zero,
+            Answers::parse(&leading_zero.to_string()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{DynSolution, Part};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How often a part is run before and while measuring it.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+/// Summary of a set of timings, all in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        let n = nanos.len();
+        if n == 0 {
+            return Self {
+                min: 0.0,
+                median: 0.0,
+                mean: 0.0,
+                stddev: 0.0,
+            };
+        }
+        let median = if n.is_multiple_of(2) {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+        } else {
+            nanos[n / 2]
+        };
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: nanos[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Timings of one part of one day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    /// Time spent in `parse`.
+    pub parse: Stats,
+    /// Time spent solving the already parsed input.
+    pub solve: Stats,
+    /// Parse and solve together, which is what baselines are compared on.
+    pub total: Stats,
+}
+
+/// Measures `part` of `day` on `input`, which is held in memory so reading files is not timed.
+pub fn bench_part(
+    day: &dyn DynSolution,
+    part: Part,
+    input: &str,
+    config: BenchConfig,
+) -> Result<BenchResult> {
+    for _ in 0..config.warmup {
+        day.solve(part, &mut input.as_bytes())?;
+    }
+
+    let mut parse_times = Vec::with_capacity(config.runs);
+    let mut solve_times = Vec::with_capacity(config.runs);
+    let mut total_times = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs {
+        let start = Instant::now();
+        let parsed = day.parse_input(&mut input.as_bytes())?;
+        let parsed_at = Instant::now();
+        day.solve_parsed(part, parsed.as_ref())?;
+        let solved_at = Instant::now();
+
+        parse_times.push(parsed_at - start);
+        solve_times.push(solved_at - parsed_at);
+        total_times.push(solved_at - start);
+    }
+
+    Ok(BenchResult {
+        day: day.day(),
+        part: match part {
+            Part::One => 1,
+            Part::Two => 2,
+        },
+        runs: config.runs,
+        parse: Stats::from_samples(&parse_times),
+        solve: Stats::from_samples(&solve_times),
+        total: Stats::from_samples(&total_times),
+    })
+}
+
+pub fn save_results(path: &Path, results: &[BenchResult]) -> Result<()> {
+    let json = serde_json::to_string_pretty(results)?;
+    fs::write(path, json + "\n").with_context(|| format!("Cannot write {}", path.display()))
+}
+
+pub fn load_results(path: &Path) -> Result<Vec<BenchResult>> {
+    let json =
+        fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("Invalid benchmark file {}", path.display()))
+}
+
+/// How a result changed against the baseline for the same day and part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline_median: f64,
+    pub median: f64,
+    /// Relative change of the total median, e.g. `0.25` for 25% slower.
+    pub change: f64,
+    pub regression: bool,
+}
+
+/// Compares the total medians against `baseline`. A result more than `threshold` (relative,
+/// e.g. `0.1` for 10%) slower than its baseline is a regression. Results without a baseline
+/// are skipped.
+pub fn compare(
+    results: &[BenchResult],
+    baseline: &[BenchResult],
+    threshold: f64,
+) -> Vec<Comparison> {
+    results
+        .iter()
+        .filter_map(|result| {
+            let base = baseline
+                .iter()
+                .find(|b| b.day == result.day && b.part == result.part)?;
+            let change = result.total.median / base.total.median - 1.0;
+            Some(Comparison {
+                day: result.day,
+                part: result.part,
+                baseline_median: base.total.median,
+                median: result.total.median,
+                change,
+                regression: change > threshold,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, median: f64) -> BenchResult {
+        let stats = Stats {
+            min: median,
+            median,
+            mean: median,
+            stddev: 0.0,
+        };
+        BenchResult {
+            day,
+            part: 1,
+            runs: 1,
+            parse: stats,
+            solve: stats,
+            total: stats,
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(1.0, stats.min);
+        assert_eq!(2.5, stats.median);
+        assert_eq!(2.5, stats.mean);
+        assert_eq!(1.25f64.sqrt(), stats.stddev);
+    }
+
+    #[test]
+    fn regressions() {
+        let baseline = [result(1, 100.0), result(2, 100.0)];
+        let current = [result(1, 105.0), result(2, 130.0), result(3, 10.0)];
+        let comparisons = compare(&current, &baseline, 0.1);
+        assert_eq!(2, comparisons.len());
+        assert!(!comparisons[0].regression);
+        assert!(comparisons[1].regression);
+        assert!((comparisons[1].change - 0.3).abs() < 1e-9);
+    }
+}
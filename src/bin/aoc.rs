@@ -0,0 +1,506 @@
+use adv_code_2024::answers::{Answers, Verdict};
+use adv_code_2024::bench::{self, BenchConfig, BenchResult};
+use adv_code_2024::days;
+use adv_code_2024::*;
+use anyhow::*;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "\
+Usage: aoc run <DAY|all> [--part 1|2] [--input <FILE|->] [--set NAME=VALUE]...
+               [--skip-examples]
+       aoc bench <DAY|all> [--part 1|2] [--input <FILE|->] [--set NAME=VALUE]...
+                 [--warmup N] [--runs N] [--json <FILE>] [--baseline <FILE>]
+                 [--threshold PCT]
+       aoc verify <DAY|all> [--part 1|2] [--record]
+       aoc new <DAY>
+
+Commands:
+  run                Solve the given day, or all of them
+  bench              Time the given day, or all of them, over several runs
+  verify             Check the given day, or all of them, against the accepted
+                     answers in answers/NN.toml
+  new                Create src/days/dayNN.rs from the template, empty input and
+                     examples files, and register the day
+
+Options:
+  --part <1|2>       Only run the given part (default: both)
+  --input <FILE|->   Read the puzzle input from FILE, or from stdin with '-'
+                     (default: input/NN.txt, not allowed with 'all')
+  --set <NAME=VALUE> Change a parameter of the day, e.g. --set turn=10 for
+                     day 16 (not allowed with 'all')
+  --skip-examples    Do not check the worked examples before solving
+
+Bench options:
+  --warmup <N>       Untimed runs before measuring (default: 3)
+  --runs <N>         Timed runs (default: 10)
+  --json <FILE>      Write the timings as JSON to FILE
+  --baseline <FILE>  Compare against timings written earlier with --json
+  --threshold <PCT>  Median slowdown against the baseline that counts as a
+                     regression, in percent (default: 10)
+
+Verify options:
+  --record           Solve parts without an accepted answer and record the
+                     result as accepted";
+
+/// Which days a `run` command covers.
+enum Target {
+    Day(u8),
+    All,
+}
+
+/// Where the puzzle input comes from.
+enum Input {
+    Default,
+    File(String),
+    Stdin,
+}
+
+fn parse_target(arg: Option<String>) -> Result<Target> {
+    Ok(match arg {
+        Some(day) if day == "all" => Target::All,
+        Some(day) => Target::Day(
+            day.parse()
+                .with_context(|| format!("Invalid day: {}", day))?,
+        ),
+        None => bail!("Missing day"),
+    })
+}
+
+fn selected_days(target: &Target) -> Result<Vec<Box<dyn DynSolution>>> {
+    Ok(match target {
+        Target::Day(number) => {
+            vec![days::get(*number).with_context(|| format!("Day {} is not solved", number))?]
+        }
+        Target::All => days::all(),
+    })
+}
+
+/// Splits the value of `--set` into name and value.
+fn parse_option(option: &str) -> Result<(String, String)> {
+    let (name, value) = option
+        .split_once('=')
+        .with_context(|| format!("Expected NAME=VALUE for --set, got {}", option))?;
+    Ok((name.to_string(), value.to_string()))
+}
+
+/// The days of `target` with `options` applied, which only a single day can take.
+fn configured_days(
+    target: &Target,
+    options: &[(String, String)],
+) -> Result<Vec<Box<dyn DynSolution>>> {
+    if let (Target::All, [_, ..]) = (target, options) {
+        bail!("--set can only be used with a single day");
+    }
+    let mut days = selected_days(target)?;
+    for day in &mut days {
+        for (name, value) in options {
+            day.set_option(name, value)
+                .with_context(|| format!("Invalid --set {}={}", name, value))?;
+        }
+    }
+    Ok(days)
+}
+
+struct RunArgs {
+    target: Target,
+    parts: Vec<Part>,
+    input: Input,
+    options: Vec<(String, String)>,
+    skip_examples: bool,
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs> {
+    let target = parse_target(args.next())?;
+
+    let mut run_args = RunArgs {
+        target,
+        parts: Part::BOTH.to_vec(),
+        input: Input::Default,
+        options: Vec::new(),
+        skip_examples: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args.next().context("Missing value for --part")?;
+                run_args.parts = vec![Part::from_number(&part)?];
+            }
+            "--input" => {
+                let path = args.next().context("Missing value for --input")?;
+                run_args.input = if path == "-" {
+                    Input::Stdin
+                } else {
+                    Input::File(path)
+                };
+            }
+            "--set" => {
+                let option = args.next().context("Missing value for --set")?;
+                run_args.options.push(parse_option(&option)?);
+            }
+            "--skip-examples" => run_args.skip_examples = true,
+            _ => bail!("Unknown argument: {}", arg),
+        }
+    }
+
+    if let (Target::All, Input::File(_) | Input::Stdin) = (&run_args.target, &run_args.input) {
+        bail!("--input can only be used with a single day");
+    }
+    Ok(run_args)
+}
+
+fn open_input(day: &dyn DynSolution, input: &Input) -> Result<Box<dyn BufRead>> {
+    Ok(match input {
+        Input::Default => {
+            let path = day.input_path();
+            Box::new(BufReader::new(
+                File::open(&path).with_context(|| format!("Cannot open {}", path))?,
+            ))
+        }
+        Input::File(path) => Box::new(BufReader::new(
+            File::open(path).with_context(|| format!("Cannot open {}", path))?,
+        )),
+        Input::Stdin => Box::new(io::stdin().lock()),
+    })
+}
+
+fn run_part(
+    day: &dyn DynSolution,
+    part: Part,
+    args: &RunArgs,
+    stdin_text: Option<&str>,
+) -> Result<()> {
+    println!("=== Part {} ===", part);
+    if !day.has_part(part) {
+        println!("Not solved yet");
+        return Ok(());
+    }
+
+    if !args.skip_examples {
+        day.run_examples(part).with_context(|| {
+            format!(
+                "Example check failed for day {:02} part {}",
+                day.day(),
+                part
+            )
+        })?;
+    }
+
+    let start = Instant::now();
+    let result = match stdin_text {
+        // stdin can only be read once, so both parts share the buffered text
+        Some(text) => day.solve(part, &mut text.as_bytes())?,
+        None => day.solve(part, &mut open_input(day, &args.input)?)?,
+    };
+    let elapsed = start.elapsed();
+    println!("Result = {}", result);
+    println!("Elapsed: {:.2?}", elapsed);
+    Ok(())
+}
+
+/// Runs the selected parts of one day, returning whether all of them succeeded.
+fn run_day(day: &dyn DynSolution, args: &RunArgs) -> Result<bool> {
+    start_day(&day.day().to_string());
+    println!("--- {} ---", day.title());
+
+    let stdin_text = match args.input {
+        Input::Stdin => Some(io::read_to_string(io::stdin())?),
+        _ => None,
+    };
+
+    let mut ok = true;
+    for &part in &args.parts {
+        if let Err(err) = run_part(day, part, args, stdin_text.as_deref()) {
+            eprintln!("Error: {:#}", err);
+            ok = false;
+        }
+    }
+    Ok(ok)
+}
+
+fn run(args: RunArgs) -> Result<bool> {
+    let mut ok = true;
+    for (i, day) in configured_days(&args.target, &args.options)?
+        .iter()
+        .enumerate()
+    {
+        if i > 0 {
+            println!();
+        }
+        ok &= run_day(day.as_ref(), &args)?;
+    }
+    Ok(ok)
+}
+
+struct BenchArgs {
+    target: Target,
+    parts: Vec<Part>,
+    input: Input,
+    options: Vec<(String, String)>,
+    config: BenchConfig,
+    json: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchArgs> {
+    let target = parse_target(args.next())?;
+
+    let mut bench_args = BenchArgs {
+        target,
+        parts: Part::BOTH.to_vec(),
+        input: Input::Default,
+        options: Vec::new(),
+        config: BenchConfig::default(),
+        json: None,
+        baseline: None,
+        threshold: 10.0,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .with_context(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--part" => bench_args.parts = vec![Part::from_number(&value()?)?],
+            "--input" => {
+                let path = value()?;
+                bench_args.input = if path == "-" {
+                    Input::Stdin
+                } else {
+                    Input::File(path)
+                };
+            }
+            "--set" => bench_args.options.push(parse_option(&value()?)?),
+            "--warmup" => {
+                bench_args.config.warmup = value()?.parse().context("Invalid --warmup")?
+            }
+            "--runs" => bench_args.config.runs = value()?.parse().context("Invalid --runs")?,
+            "--json" => bench_args.json = Some(value()?),
+            "--baseline" => bench_args.baseline = Some(value()?),
+            "--threshold" => {
+                bench_args.threshold = value()?.parse().context("Invalid --threshold")?
+            }
+            _ => bail!("Unknown argument: {}", arg),
+        }
+    }
+
+    if let (Target::All, Input::File(_) | Input::Stdin) = (&bench_args.target, &bench_args.input) {
+        bail!("--input can only be used with a single day");
+    }
+    if bench_args.config.runs == 0 {
+        bail!("--runs must be at least 1");
+    }
+    Ok(bench_args)
+}
+
+fn read_input(day: &dyn DynSolution, input: &Input) -> Result<String> {
+    Ok(match input {
+        Input::Default => {
+            let path = day.input_path();
+            std::fs::read_to_string(&path).with_context(|| format!("Cannot read {}", path))?
+        }
+        Input::File(path) => {
+            std::fs::read_to_string(path).with_context(|| format!("Cannot read {}", path))?
+        }
+        Input::Stdin => io::read_to_string(io::stdin())?,
+    })
+}
+
+fn nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos as u64)
+}
+
+fn print_bench(result: &BenchResult) {
+    println!(
+        "Day {:02} part {}: median {:.2?} (min {:.2?}, mean {:.2?} ± {:.2?}) \
+         = parse {:.2?} + solve {:.2?}",
+        result.day,
+        result.part,
+        nanos(result.total.median),
+        nanos(result.total.min),
+        nanos(result.total.mean),
+        nanos(result.total.stddev),
+        nanos(result.parse.median),
+        nanos(result.solve.median),
+    );
+}
+
+/// Benchmarks the selected days, returning whether none of them regressed.
+fn bench(args: BenchArgs) -> Result<bool> {
+    // Loaded first, so `--json` may write the new results to the same file
+    let baseline = match &args.baseline {
+        Some(path) => Some(bench::load_results(Path::new(path))?),
+        None => None,
+    };
+
+    let mut results = Vec::new();
+    for day in &configured_days(&args.target, &args.options)? {
+        let day = day.as_ref();
+        let input = read_input(day, &args.input)?;
+        for &part in &args.parts {
+            if !day.has_part(part) {
+                continue;
+            }
+            let result = bench::bench_part(day, part, &input, args.config)
+                .with_context(|| format!("Day {:02} part {} failed", day.day(), part))?;
+            print_bench(&result);
+            results.push(result);
+        }
+    }
+
+    if let Some(path) = &args.json {
+        bench::save_results(Path::new(path), &results)?;
+    }
+
+    let mut ok = true;
+    if let Some(baseline) = &baseline {
+        println!();
+        for comparison in bench::compare(&results, baseline, args.threshold / 100.0) {
+            println!(
+                "Day {:02} part {}: {:.2?} -> {:.2?} ({:+.1}%){}",
+                comparison.day,
+                comparison.part,
+                nanos(comparison.baseline_median),
+                nanos(comparison.median),
+                comparison.change * 100.0,
+                if comparison.regression {
+                    "  REGRESSION"
+                } else {
+                    ""
+                }
+            );
+            ok &= !comparison.regression;
+        }
+    }
+    Ok(ok)
+}
+
+struct VerifyArgs {
+    target: Target,
+    parts: Vec<Part>,
+    record: bool,
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs> {
+    let mut verify_args = VerifyArgs {
+        target: parse_target(args.next())?,
+        parts: Part::BOTH.to_vec(),
+        record: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args.next().context("Missing value for --part")?;
+                verify_args.parts = vec![Part::from_number(&part)?];
+            }
+            "--record" => verify_args.record = true,
+            _ => bail!("Unknown argument: {}", arg),
+        }
+    }
+    Ok(verify_args)
+}
+
+/// Checks the selected days against their accepted answers, returning whether none of them
+/// mismatched or failed.
+fn verify(args: VerifyArgs) -> Result<bool> {
+    let (mut matched, mut mismatched, mut missing, mut recorded, mut failed) = (0, 0, 0, 0, 0);
+    for day in &selected_days(&args.target)? {
+        let day = day.as_ref();
+        let path = day.answers_path();
+        let mut answers = Answers::load(Path::new(&path))?;
+        let recorded_before = recorded;
+
+        for &part in &args.parts {
+            let label = format!("Day {:02} part {}", day.day(), part);
+            // Never solved, so never recorded either
+            if !day.has_part(part) {
+                println!("{}: not solved", label);
+                continue;
+            }
+            if answers.get(part).is_none() && !args.record {
+                println!("{}: missing", label);
+                missing += 1;
+                continue;
+            }
+
+            let result =
+                open_input(day, &Input::Default).and_then(|mut input| day.solve(part, &mut input));
+            let result = match result {
+                std::result::Result::Ok(result) => result,
+                Err(err) => {
+                    println!("{}: FAILED: {:#}", label, err);
+                    failed += 1;
+                    continue;
+                }
+            };
+            match answers.verify(part, &result) {
+                Verdict::Match => {
+                    println!("{}: ok ({})", label, result);
+                    matched += 1;
+                }
+                Verdict::Mismatch { expected } => {
+                    println!("{}: MISMATCH, expected {}, got {}", label, expected, result);
+                    mismatched += 1;
+                }
+                Verdict::Missing => {
+                    println!("{}: recorded {}", label, result);
+                    answers.set(part, result);
+                    recorded += 1;
+                }
+            }
+        }
+
+        if recorded > recorded_before {
+            answers.save(Path::new(&path))?;
+        }
+    }
+
+    println!();
+    println!(
+        "{} matched, {} mismatched, {} missing, {} recorded, {} failed",
+        matched, mismatched, missing, recorded, failed
+    );
+    Ok(mismatched == 0 && failed == 0)
+}
+
+fn new(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let day = args.next().context("Missing day")?;
+    let day = day
+        .parse()
+        .with_context(|| format!("Invalid day: {}", day))?;
+    if let Some(arg) = args.next() {
+        bail!("Unknown argument: {}", arg);
+    }
+
+    for path in scaffold::new_day(&std::env::current_dir()?, day)? {
+        println!("Created {}", path.display());
+    }
+    Ok(())
+}
+
+fn main() -> Result<ExitCode> {
+    let mut args = std::env::args().skip(1);
+    let succeeded = match args.next().as_deref() {
+        Some("run") => run(parse_run_args(args).context(USAGE)?)?,
+        Some("bench") => bench(parse_bench_args(args).context(USAGE)?)?,
+        Some("verify") => verify(parse_verify_args(args).context(USAGE)?)?,
+        Some("new") => {
+            new(args)?;
+            true
+        }
+        Some("-h" | "--help") => {
+            println!("{}", USAGE);
+            true
+        }
+        _ => bail!("{}", USAGE),
+    };
+    Ok(if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,50 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+// TODO: Paste the example from the puzzle description into examples/NN.txt
+const TEST: &str = include_str!("../../examples/NN.txt");
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = NN;
+    const TITLE: &'static str = "<TITLE>"; // TODO: Fill the title
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        // TODO: Parse the input into something both parts can use
+        Ok(reader.lines().collect::<std::io::Result<_>>()?)
+    }
+
+    fn part1(&self, _input: &Self::Input) -> Result<usize> {
+        // TODO: Solve Part 1 of the puzzle
+        bail!("Not solved yet")
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<usize> {
+        // TODO: Solve Part 2 of the puzzle
+        bail!("Not solved yet")
+    }
+
+    // TODO: Remove once part 2 is solved
+    fn has_part2(&self) -> bool {
+        false
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                // TODO: Set the expected answer for the test input
+                ensure_eq!(0, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(0, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::*;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+const TEST: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+fn split_lists(reader: &mut dyn BufRead) -> Result<(Vec<isize>, Vec<isize>)> {
+    let input = std::io::read_to_string(reader)?;
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for line in parse::lines(&input) {
+        match line.ints()?[..] {
+            [l_val, r_val] => {
+                left.push(l_val);
+                right.push(r_val);
+            }
+            _ => bail!(line.error("Expected two numbers")),
+        }
+    }
+
+    Ok((left, right))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<isize>, Vec<isize>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        split_lists(reader)
+    }
+
+    fn part1(&self, (left, right): &Self::Input) -> Result<usize> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+
+        left.sort();
+        right.sort();
+
+        let answer = left
+            .iter()
+            .zip(right)
+            .map(|(a, b)| { isize::abs(*a - b) } as usize)
+            .sum();
+
+        Ok(answer)
+    }
+
+    fn part2(&self, (left, right): &Self::Input) -> Result<usize> {
+        let mut right_cout_map = HashMap::new();
+        for r_val in right {
+            match right_cout_map.get(r_val) {
+                Some(count) => {
+                    right_cout_map.insert(r_val, count + 1);
+                }
+                None => {
+                    right_cout_map.insert(r_val, 1);
+                }
+            }
+        }
+
+        let mut similarity_score = 0;
+        for l_val in left {
+            let count = if let Some(c) = right_cout_map.get(l_val) {
+                *c
+            } else {
+                0
+            };
+            similarity_score += l_val * count;
+        }
+
+        Ok(similarity_score as usize)
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(11, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(31, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
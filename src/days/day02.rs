@@ -0,0 +1,89 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+const TEST: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+fn check_line(nums: &[isize]) -> usize {
+    let mut dir = 0;
+    for (a, b) in nums.iter().zip(nums.iter().skip(1)) {
+        let dist = a - b;
+        if dir == 0 {
+            dir = if dist > 0 { 1 } else { -1 };
+        } else {
+            if dir * dist < 0 {
+                return 0;
+            }
+        }
+        if dist.abs() < 1 || dist.abs() > 3 {
+            return 0;
+        }
+    }
+    1
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<isize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let mut reports = Vec::new();
+        for line in reader.lines() {
+            let nums = line?
+                .split(" ")
+                .map(|num| num.parse::<isize>())
+                .collect::<std::result::Result<_, _>>()?;
+            reports.push(nums);
+        }
+        Ok(reports)
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Result<usize> {
+        let safe_count = reports.iter().map(|nums| check_line(nums)).sum();
+
+        Ok(safe_count)
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Result<usize> {
+        let safe_count = reports
+            .iter()
+            .map(|nums| {
+                for i in 0..nums.len() {
+                    let mut local_nums = nums.clone();
+                    local_nums.remove(i);
+                    if check_line(&local_nums) == 1 {
+                        return 1;
+                    }
+                }
+                0
+            })
+            .sum();
+
+        Ok(safe_count)
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(2, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(4, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
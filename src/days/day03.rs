@@ -0,0 +1,94 @@
+use crate::*;
+use anyhow::*;
+use regex::Regex;
+use std::io::BufRead;
+
+const TEST: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+const TEST_2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+/// The instructions the corrupted memory still contains, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+fn read_instructions(input: &str) -> Result<Vec<Instruction>> {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)")?;
+    let mut instructions = Vec::new();
+    for line in parse::lines(input) {
+        for captures in re.captures_iter(line.text) {
+            let number = |group: usize| -> Result<Option<usize>> {
+                let Some(m) = captures.get(group) else {
+                    return Ok(None);
+                };
+                let column = line.column + line.text[..m.start()].chars().count();
+                Ok(Some(error::parse_at(m.as_str(), line.number, column)?))
+            };
+            instructions.push(match (number(1)?, number(2)?) {
+                (Some(a), Some(b)) => Instruction::Mul(a, b),
+                _ if &captures[0] == "do()" => Instruction::Do,
+                _ => Instruction::Dont,
+            });
+        }
+    }
+    Ok(instructions)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        read_instructions(&std::io::read_to_string(reader)?)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<usize> {
+        let mut sum = 0;
+        for instruction in instructions {
+            if let Instruction::Mul(a, b) = instruction {
+                sum += a * b;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<usize> {
+        let mut sum = 0;
+        // everything after a do() is enabled again, across lines as well
+        let mut enabled = true;
+        for instruction in instructions {
+            match instruction {
+                Instruction::Mul(a, b) if enabled => sum += a * b,
+                Instruction::Mul(..) => {}
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+            }
+        }
+        Ok(sum)
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(161, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(48, self.part2_str(TEST_2)?);
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,12 +1,6 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-const DAY: &str = "04";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use std::io::BufRead;
 
 const TEST: &str = "\
 MMMSXXMASM
@@ -21,7 +15,7 @@ MAMMMXMMMM
 MXMXAXMASX
 ";
 
-fn count_xmas_in_field(field: &Vec<Vec<char>>) -> usize {
+fn count_xmas_in_field(field: &[Vec<char>]) -> usize {
     let mut count = 0;
     let num_rows = field.len();
     for row_idx in 0..num_rows {
@@ -107,7 +101,7 @@ fn count_xmas_in_field(field: &Vec<Vec<char>>) -> usize {
     count
 }
 
-fn count_x_mas_in_field(field: &Vec<Vec<char>>) -> usize {
+fn count_x_mas_in_field(field: &[Vec<char>]) -> usize {
     let mut count = 0;
     let num_rows = field.len();
     for row_idx in 1..num_rows - 1 {
@@ -177,46 +171,42 @@ fn count_x_mas_in_field(field: &Vec<Vec<char>>) -> usize {
     count
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    //region Part 1
-    println!("=== Part 1 ===");
+pub struct Day04;
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let char_field: Vec<Vec<char>> = reader
-            .lines()
-            .map(|x| x.unwrap().chars().collect())
-            .collect();
-        let answer = count_xmas_in_field(&char_field);
-        Ok(answer)
-    }
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
 
-    assert_eq!(18, part1(BufReader::new(TEST.as_bytes()))?);
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        Ok(parse::char_rows(&input, "a letter", |c| {
+            c.is_ascii_uppercase()
+        })?)
+    }
 
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let char_field: Vec<Vec<char>> = reader
-            .lines()
-            .map(|x| x.unwrap().chars().collect())
-            .collect();
-        let answer = count_x_mas_in_field(&char_field);
+    fn part1(&self, char_field: &Self::Input) -> Result<usize> {
+        let answer = count_xmas_in_field(char_field);
         Ok(answer)
     }
 
-    assert_eq!(9, part2(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
+    fn part2(&self, char_field: &Self::Input) -> Result<usize> {
+        let answer = count_x_mas_in_field(char_field);
+        Ok(answer)
+    }
 
-    Ok(())
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(18, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(9, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,147 @@
+use crate::*;
+use anyhow::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+const TEST: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+/// The page ordering rules, by page that must come first, and the updates.
+type PrintQueue = (HashMap<usize, Vec<usize>>, Vec<Vec<usize>>);
+
+fn process_input(reader: &mut dyn BufRead) -> Result<PrintQueue> {
+    let input = std::io::read_to_string(reader)?;
+    let [rules, updates] = parse::n_sections(&input)?;
+
+    let mut pair_map = HashMap::new();
+    for line in &rules.lines {
+        let [a, b] = line.record("{}|{}")?;
+        pair_map.entry(a).or_insert_with(Vec::new).push(b);
+    }
+
+    let update_list = updates
+        .lines
+        .iter()
+        .map(|line| line.list(','))
+        .collect::<Result<_, _>>()?;
+
+    Ok((pair_map, update_list))
+}
+
+fn is_update_correct(rules: &HashMap<usize, Vec<usize>>, update: &[usize]) -> bool {
+    let mut idx_map = HashMap::with_capacity(update.len());
+    for (idx, val) in update.iter().enumerate() {
+        idx_map.insert(*val, idx);
+    }
+
+    for (idx, num) in update.iter().enumerate() {
+        if let Some(other_vec) = rules.get(num) {
+            for other in other_vec {
+                if let Some(other_idx) = idx_map.get(other) {
+                    if idx >= *other_idx {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+
+fn rule_cmp(a: &usize, b: &usize, rules: &HashMap<usize, Vec<usize>>) -> Ordering {
+    match rules.get(a) {
+        Some(others) => {
+            if others.contains(b) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+        None => Ordering::Greater,
+    }
+}
+
+fn correct_update(rules: &HashMap<usize, Vec<usize>>, update: &[usize]) -> Vec<usize> {
+    let mut res = update.to_vec();
+    res.sort_by(|x, x1| rule_cmp(x, x1, rules));
+    res
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = PrintQueue;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        process_input(reader)
+    }
+
+    fn part1(&self, (ordering, pages): &Self::Input) -> Result<usize> {
+        let correct_pages = pages
+            .iter()
+            .filter(|x| is_update_correct(ordering, x))
+            .collect::<Vec<_>>();
+        let middle_sum = correct_pages.iter().map(|x1| x1[x1.len() / 2]).sum();
+        Ok(middle_sum)
+    }
+
+    fn part2(&self, (ordering, pages): &Self::Input) -> Result<usize> {
+        let incorrect_pages = pages
+            .iter()
+            .filter(|x| !is_update_correct(ordering, x))
+            .collect::<Vec<_>>();
+        let corrected_pages = incorrect_pages
+            .iter()
+            .map(|x1| correct_update(ordering, x1))
+            .collect::<Vec<_>>();
+        let middle_sum = corrected_pages.iter().map(|x1| x1[x1.len() / 2]).sum();
+        Ok(middle_sum)
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(143, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(123, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use crate::*;
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+const TEST: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Marker {
+    position: Point,
+    direction: Direction,
+}
+
+impl Marker {
+    fn new(position: Point, direction: Direction) -> Self {
+        Self {
+            position,
+            direction,
+        }
+    }
+}
+
+fn count_individual_positions(walked_fields: &HashSet<Marker>) -> usize {
+    filter_positions(walked_fields).len()
+}
+
+fn filter_positions(walked_fields: &HashSet<Marker>) -> HashSet<Point> {
+    let mut positions = HashSet::new();
+    for marker in walked_fields.iter() {
+        positions.insert(marker.position);
+    }
+    positions
+}
+
+fn walk_field(start: Point, field: &Grid<char>) -> (usize, bool, HashSet<Marker>) {
+    let mut walked_fields = HashSet::new();
+    let mut current_field = start;
+    let mut current_direction = Direction::Up;
+    loop {
+        if !walked_fields.insert(Marker::new(current_field, current_direction)) {
+            return (
+                count_individual_positions(&walked_fields),
+                true,
+                walked_fields,
+            );
+        }
+
+        let Some(next_field) = current_field.checked_step(current_direction, field.size()) else {
+            break;
+        };
+
+        if field[next_field] == '#' {
+            current_direction = current_direction.turn_right();
+            continue;
+        }
+        current_field = next_field;
+    }
+    (
+        count_individual_positions(&walked_fields),
+        false,
+        walked_fields,
+    )
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    /// The map and the guard's starting position.
+    type Input = (Grid<char>, Point);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let field = Grid::from_chars(reader)?;
+        let start = field
+            .find(|&cell| cell == '^')
+            .context("No guard '^' on the map")?;
+        Ok((field, start))
+    }
+
+    fn part1(&self, (field, start): &Self::Input) -> Result<usize> {
+        let answer = walk_field(*start, field);
+        Ok(answer.0)
+    }
+
+    fn part2(&self, (field, start): &Self::Input) -> Result<usize> {
+        let start = *start;
+
+        let mut loop_count = 0;
+        let (_, _, walked_fields) = walk_field(start, field);
+        for position in filter_positions(&walked_fields).iter() {
+            if *position == start {
+                continue;
+            }
+            let mut test_field = field.clone();
+            test_field[*position] = '#';
+            let (_, looped, _) = walk_field(start, &test_field);
+            if looped {
+                loop_count += 1;
+            }
+        }
+
+        Ok(loop_count)
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(41, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(6, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
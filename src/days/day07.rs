@@ -1,12 +1,6 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-const DAY: &str = "07"; // TODO: Fill the day
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use std::io::BufRead;
 
 const TEST: &str = "\
 190: 10 19
@@ -21,7 +15,7 @@ const TEST: &str = "\
 ";
 
 fn check_slice_add_mul(result: usize, current_result: usize, rest_values: &[usize]) -> bool {
-    if rest_values.len() == 0 {
+    if rest_values.is_empty() {
         return result == current_result;
     }
 
@@ -46,7 +40,7 @@ fn check_slice_add_mul(result: usize, current_result: usize, rest_values: &[usiz
 }
 
 fn check_slice_add_mul_concat(result: usize, current_result: usize, rest_values: &[usize]) -> bool {
-    if rest_values.len() == 0 {
+    if rest_values.is_empty() {
         return result == current_result;
     }
 
@@ -58,7 +52,6 @@ fn check_slice_add_mul_concat(result: usize, current_result: usize, rest_values:
     };
     let next_concat: usize = concatenate_integers(current_result, rest_values[0]);
 
-
     let mut add_res = false;
     let mut mul_res = false;
     let mut concat_res = false;
@@ -88,67 +81,59 @@ fn concatenate_integers(a: usize, b: usize) -> usize {
     a * 10_usize.pow(digits) + b
 }
 
-fn read_calibrations<R: BufRead>(reader: R) -> Vec<(usize, Vec<usize>)>{
-    reader.lines()
-        .map(|x| {
-            let line = x.unwrap();
-            let mut parts = line.split(": ");
-            let result = parts.next().unwrap().parse().unwrap();
-            let values = parts
-                .next()
-                .unwrap()
-                .split(" ")
-                .map(|x| x.parse().unwrap())
-                .collect();
-            (result, values)
-        })
-        .collect()
+fn read_calibrations(reader: &mut dyn BufRead) -> Result<Vec<(usize, Vec<usize>)>> {
+    let input = std::io::read_to_string(reader)?;
+    let mut calibrations = Vec::new();
+    for line in parse::lines(&input) {
+        let (result, values) = line.split_once(": ")?;
+        calibrations.push((result.parse()?, values.list(' ')?));
+    }
+    Ok(calibrations)
 }
-fn main() -> Result<()> {
-    start_day(DAY);
 
-    //region Part 1
-    println!("=== Part 1 ===");
+pub struct Day07;
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
 
-        let calibrations: Vec<(usize, Vec<usize>)> = read_calibrations(reader);
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        read_calibrations(reader)
+    }
+
+    fn part1(&self, calibrations: &Self::Input) -> Result<usize> {
         let answer = calibrations
             .iter()
             .filter(|(result, values)| check_slice_add_mul(*result, 0, values))
-            .map(|(result, _)| *result).sum();
+            .map(|(result, _)| *result)
+            .sum();
 
         Ok(answer)
     }
 
-    assert_eq!(3749, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let calibrations: Vec<(usize, Vec<usize>)> = read_calibrations(reader);
-
+    fn part2(&self, calibrations: &Self::Input) -> Result<usize> {
         let answer = calibrations
             .iter()
             .filter(|(result, values)| check_slice_add_mul_concat(*result, 0, values))
-            .map(|(result, _)| *result).sum();
+            .map(|(result, _)| *result)
+            .sum();
 
         Ok(answer)
     }
 
-    assert_eq!(11387, part2(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    Ok(())
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(3749, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(11387, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
 }
@@ -1,13 +1,7 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-const DAY: &str = "08";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use std::io::BufRead;
 
 const TEST: &str = "\
 ............
@@ -24,16 +18,14 @@ const TEST: &str = "\
 ............
 ";
 
-fn read_char_field<R: BufRead>(reader: R) -> Result<Vec<Vec<char>>> {
-    let mut field = Vec::new();
-    for line in reader.lines() {
-        let line = line?;
-        field.push(line.chars().collect());
-    }
-    Ok(field)
+fn read_char_field(reader: &mut dyn BufRead) -> Result<Vec<Vec<char>>> {
+    let input = std::io::read_to_string(reader)?;
+    Ok(parse::char_rows(&input, "an antenna or '.'", |c| {
+        c.is_ascii_alphanumeric() || c == '.'
+    })?)
 }
 
-fn make_frequency_map(field: &Vec<Vec<char>>) -> HashMap<char, Vec<(usize, usize)>> {
+fn make_frequency_map(field: &[Vec<char>]) -> HashMap<char, Vec<(usize, usize)>> {
     let mut map = HashMap::new();
     for (y, row) in field.iter().enumerate() {
         for (x, &c) in row.iter().enumerate() {
@@ -47,7 +39,7 @@ fn make_frequency_map(field: &Vec<Vec<char>>) -> HashMap<char, Vec<(usize, usize
 }
 
 fn get_antinodes_for_frequency(
-    antenna_positions: &Vec<(usize, usize)>,
+    antenna_positions: &[(usize, usize)],
     field_size: (usize, usize),
     ignore_distance: bool,
 ) -> Vec<(usize, usize)> {
@@ -61,7 +53,7 @@ fn get_antinodes_for_frequency(
 
             let dx = x2 as isize - x1 as isize;
             let dy = y2 as isize - y1 as isize;
-            let gcd = greatest_common_divisor(dx.abs() as usize, dy.abs() as usize);
+            let gcd = greatest_common_divisor(dx.unsigned_abs(), dy.unsigned_abs());
             let dx_step = dx / gcd as isize;
             let dy_step = dy / gcd as isize;
 
@@ -107,25 +99,16 @@ fn is_step_in_field(x: usize, y: usize, dx: isize, dy: isize, field_size: (usize
     new_x >= 0 && new_x < width as isize && new_y >= 0 && new_y < height as isize
 }
 
+/// Whether `point` is twice as far from one antenna as from the other, in the same direction.
 fn dist_is_two_to_one(pos_1: (usize, usize), pos_2: (usize, usize), point: (usize, usize)) -> bool {
-    let dx1 = pos_1.0 as isize - point.0 as isize;
-    let dy1 = pos_1.1 as isize - point.1 as isize;
-    let dx2 = pos_2.0 as isize - point.0 as isize;
-    let dy2 = pos_2.1 as isize - point.1 as isize;
-
-    if dx1 == 0 && dx2 == 0 {
-        return (dy1 / dy2 == 2) || (dy2 / dy1 == 2);
-    } else if (dx1 == 0 && dx2 != 0) || (dx1 != 0 && dx2 == 0) {
-        return false;
-    }
-
-    if dy1 == 0 && dy2 == 0 {
-        return (dx1 / dx2 == 2) || (dx2 / dx1 == 2);
-    } else if (dy1 == 0 && dy2 != 0) || (dy1 != 0 && dy2 == 0) {
-        return false;
-    }
-
-    (dx1 / dx2 == 2 && dy1 / dy2 == 2) || (dx2 / dx1 == 2 && dy2 / dy1 == 2)
+    let offset = |pos: (usize, usize)| {
+        (
+            pos.0 as isize - point.0 as isize,
+            pos.1 as isize - point.1 as isize,
+        )
+    };
+    let (d1, d2) = (offset(pos_1), offset(pos_2));
+    d1 == (2 * d2.0, 2 * d2.1) || d2 == (2 * d1.0, 2 * d1.1)
 }
 
 fn greatest_common_divisor(a: usize, b: usize) -> usize {
@@ -146,15 +129,22 @@ fn greatest_common_divisor(a: usize, b: usize) -> usize {
     a
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
 
-    //region Part 1
-    println!("=== Part 1 ===");
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        read_char_field(reader)
+    }
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let field = read_char_field(reader)?;
-        let frequency_map = make_frequency_map(&field);
+    fn part1(&self, field: &Self::Input) -> Result<usize> {
+        let frequency_map = make_frequency_map(field);
         // println!("Antennas positioned by frequency: {:?}", frequency_map);
         let mut antinodes: Vec<Vec<(usize, usize)>> = Vec::new();
         for (_, positions) in frequency_map.iter() {
@@ -176,19 +166,8 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(14, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let field = read_char_field(reader)?;
-        let frequency_map = make_frequency_map(&field);
+    fn part2(&self, field: &Self::Input) -> Result<usize> {
+        let frequency_map = make_frequency_map(field);
         // println!("Antennas positioned by frequency: {:?}", frequency_map);
         let mut antinodes: Vec<Vec<(usize, usize)>> = Vec::new();
         for (_, positions) in frequency_map.iter() {
@@ -210,12 +189,15 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(34, part2(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    Ok(())
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(14, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(34, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
 }
@@ -1,13 +1,6 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use itertools::Itertools;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-const DAY: &str = "09";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use std::io::BufRead;
 
 const TEST: &str = "\
 2333133121414131402
@@ -35,6 +28,7 @@ impl DiskFile {
         }
     }
 
+    #[allow(dead_code)]
     fn is_end(&self) -> bool {
         self.next.is_none()
     }
@@ -48,6 +42,7 @@ impl DiskFile {
             }
     }
 
+    #[allow(dead_code)]
     fn is_in_file(&self, address: usize) -> bool {
         if self.length == 0 {
             return match &self.next {
@@ -69,10 +64,10 @@ impl DiskFile {
 
     fn take_space(&mut self, space: &FreeSpace) -> (Option<FreeSpace>, bool) {
         if self.length == 0 {
-            return (Some(space.clone()), true);
+            return (Some(*space), true);
         }
         if space.start >= (self.start + self.length) {
-            return (Some(space.clone()), true);
+            return (Some(*space), true);
         }
 
         if self.length == space.length {
@@ -105,23 +100,15 @@ struct FreeSpace {
     pub length: usize,
 }
 
-fn make_disk_map(input: String) -> (Vec<DiskFile>, Vec<FreeSpace>) {
-    let mut input = input;
-    if input.len() % 2 != 0 {
-        input.push('0');
-    }
-    let mut files = Vec::with_capacity(input.len() / 2);
-    let mut free_space = Vec::with_capacity(input.len() / 2);
+/// The files and free spaces of the disk map, given as its alternating lengths.
+fn make_disk_map(lengths: &[usize]) -> (Vec<DiskFile>, Vec<FreeSpace>) {
+    let mut files = Vec::with_capacity(lengths.len() / 2 + 1);
+    let mut free_space = Vec::with_capacity(lengths.len() / 2);
 
     let mut address = 0;
-    for (i, (c_f, c_s)) in input
-        .chars()
-        .step_by(2)
-        .zip(input.chars().skip(1).step_by(2))
-        .enumerate()
-    {
-        let file_len = usize::from_str_radix(format!("{c_f}").as_str(), 10).unwrap();
-        let space_len = usize::from_str_radix(format!("{c_s}").as_str(), 10).unwrap();
+    for (i, pair) in lengths.chunks(2).enumerate() {
+        let file_len = pair[0];
+        let space_len = pair.get(1).copied().unwrap_or(0);
 
         files.push(DiskFile {
             id: i,
@@ -143,17 +130,18 @@ fn make_disk_map(input: String) -> (Vec<DiskFile>, Vec<FreeSpace>) {
     (files, free_space)
 }
 
-fn print_files(files: &Vec<DiskFile>, space: &Vec<FreeSpace>) {
+#[allow(dead_code)]
+fn print_files(files: &[DiskFile], space: &[FreeSpace]) {
     let total_file_length: usize = files.iter().map(|x| x.remaining_file_length()).sum();
     let total_space_length: usize = space.iter().map(|x1| x1.length).sum();
     let str_len = total_file_length + total_space_length;
 
     let mut out_vec = vec!['.'; str_len];
-    for i in 0..str_len {
+    for (i, out) in out_vec.iter_mut().enumerate() {
         'file_loop: for file in files {
             if file.is_in_file(i) {
                 let file_id: Vec<char> = format!("{}", file.id).chars().collect();
-                out_vec[i] = file_id[file_id.len() - 1];
+                *out = file_id[file_id.len() - 1];
                 break 'file_loop;
             }
         }
@@ -163,15 +151,30 @@ fn print_files(files: &Vec<DiskFile>, space: &Vec<FreeSpace>) {
     println!("{}", out_vec.iter().collect::<String>())
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    /// The dense disk map.
+    /// Lengths of the files and free spaces, alternating.
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    //region Part 1
-    println!("=== Part 1 ===");
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let line = reader.lines().next().context("Empty input")??;
+        let line = line.trim_end();
+        let mut lengths = Vec::with_capacity(line.len());
+        for (x, c) in line.char_indices() {
+            lengths.push(error::parse_at(&line[x..x + c.len_utf8()], 1, x + 1)?);
+        }
+        Ok(lengths)
+    }
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let line = reader.lines().next().unwrap()?;
-        let (mut files, mut space) = make_disk_map(line);
+    fn part1(&self, lengths: &Self::Input) -> Result<usize> {
+        let (files, mut space) = make_disk_map(lengths);
 
         space.reverse();
         let mut space_stack = space
@@ -224,26 +227,15 @@ fn main() -> Result<()> {
         Ok(sum)
     }
 
-    assert_eq!(1928, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let line = reader.lines().next().unwrap()?;
-        let (mut files, mut space_stack) = make_disk_map(line);
+    fn part2(&self, lengths: &Self::Input) -> Result<usize> {
+        let (files, mut space_stack) = make_disk_map(lengths);
 
         let mut files_rev = files.clone();
         files_rev.reverse();
 
         'file_loop: for file in &mut files_rev {
             for spc_idx in 0..space_stack.len() {
-                if space_stack[spc_idx].length >= file.length{
+                if space_stack[spc_idx].length >= file.length {
                     let (remaining_space, _) = file.take_space(&space_stack[spc_idx]);
                     if let Some(remaining_space) = remaining_space {
                         space_stack[spc_idx] = remaining_space;
@@ -262,12 +254,15 @@ fn main() -> Result<()> {
         Ok(sum)
     }
 
-    assert_eq!(2858, part2(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    Ok(())
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(1928, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(2858, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
 }
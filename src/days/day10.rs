@@ -1,13 +1,7 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-const DAY: &str = "10";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use std::io::BufRead;
 
 const TEST: &str = "\
 89010123
@@ -20,7 +14,6 @@ const TEST: &str = "\
 10456732
 ";
 
-
 const NEIGHBORS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
 
 fn is_in_field(pos: (isize, isize), field_size: (isize, isize)) -> bool {
@@ -44,10 +37,10 @@ fn is_correct_next_char(current: char, next: char) -> bool {
 }
 
 fn count_complete_trails(
-    field: &Vec<Vec<char>>,
+    field: &[Vec<char>],
     start: (isize, isize),
-    mut visited: HashSet<(isize, isize)>
-) -> (HashSet<(isize, isize)>,usize) {
+    mut visited: HashSet<(isize, isize)>,
+) -> (HashSet<(isize, isize)>, usize) {
     if !visited.insert(start) {
         return (HashSet::new(), 0);
     }
@@ -68,7 +61,7 @@ fn count_complete_trails(
                 field[next.0 as usize][next.1 as usize],
             )
         {
-            let (path_set, path_rating) =count_complete_trails(field, next, visited.clone());
+            let (path_set, path_rating) = count_complete_trails(field, next, visited.clone());
             top_set.extend(path_set);
             rating += path_rating;
         }
@@ -76,11 +69,11 @@ fn count_complete_trails(
     (top_set, rating)
 }
 
-fn list_starts(field: &Vec<Vec<char>>) -> Vec<(isize, isize)> {
+fn list_starts(field: &[Vec<char>]) -> Vec<(isize, isize)> {
     let mut starts = Vec::new();
-    for row in 0..field.len() {
-        for col in 0..field[row].len() {
-            if field[row][col] == '0' {
+    for (row, line) in field.iter().enumerate() {
+        for (col, &height) in line.iter().enumerate() {
+            if height == '0' {
                 starts.push((row as isize, col as isize));
             }
         }
@@ -88,60 +81,54 @@ fn list_starts(field: &Vec<Vec<char>>) -> Vec<(isize, isize)> {
     starts
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+pub struct Day10;
 
-    //region Part 1
-    println!("=== Part 1 ===");
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let field = reader
-            .lines()
-            .map(|line| line.map(|l| l.chars().collect::<Vec<_>>()))
-            .collect::<Result<Vec<_>, _>>()?;
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        Ok(parse::char_rows(&input, "a height or '.'", |c| {
+            c.is_ascii_digit() || c == '.'
+        })?)
+    }
 
-        let starts = list_starts(&field);
+    fn part1(&self, field: &Self::Input) -> Result<usize> {
+        let starts = list_starts(field);
 
         let mut sum_score = 0;
         for start in starts {
-            let (top_set, _) = count_complete_trails(&field, start, HashSet::with_capacity(10));
+            let (top_set, _) = count_complete_trails(field, start, HashSet::with_capacity(10));
             sum_score += top_set.len();
         }
         Ok(sum_score)
     }
 
-    assert_eq!(36, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let field = reader
-            .lines()
-            .map(|line| line.map(|l| l.chars().collect::<Vec<_>>()))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let starts = list_starts(&field);
+    fn part2(&self, field: &Self::Input) -> Result<usize> {
+        let starts = list_starts(field);
 
         let mut sum_score = 0;
         for start in starts {
-            let (_, rating) = count_complete_trails(&field, start, HashSet::with_capacity(10));
+            let (_, rating) = count_complete_trails(field, start, HashSet::with_capacity(10));
             sum_score += rating;
         }
         Ok(sum_score)
     }
 
-    assert_eq!(81, part2(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    Ok(())
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(36, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(81, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
 }
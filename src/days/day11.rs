@@ -1,15 +1,9 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::thread;
 
-const DAY: &str = "11";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
-
 const TEST: &str = "\
 125 17
 ";
@@ -42,7 +36,7 @@ fn blink(num: usize) -> BlinkResult {
     let d_cont = count_digits(num);
     if num == 0 {
         BlinkResult::One(1)
-    } else if d_cont % 2 == 0 {
+    } else if d_cont.is_multiple_of(2) {
         let (left, right) = split_number(num, d_cont);
         BlinkResult::Two(left, right)
     } else {
@@ -90,14 +84,13 @@ fn blink_deep(num: usize, depth: usize, cache: &mut HashMap<(usize, usize), usiz
 }
 
 fn next_fitting_multiple(number: usize, base: usize) -> usize {
-    if number % base == 0 {
+    if number.is_multiple_of(base) {
         return number;
     }
 
     let quotient = number / base;
-    let multiple = (quotient + 1) * base;
 
-    multiple
+    (quotient + 1) * base
 }
 
 fn blink_map(map: HashMap<usize, usize>) -> HashMap<usize, usize> {
@@ -140,18 +133,49 @@ fn blink_map(map: HashMap<usize, usize>) -> HashMap<usize, usize> {
 
 const MAX_NUM_THREADS: usize = 4;
 
-fn main() -> Result<()> {
-    start_day(DAY);
+/// Counts the stones after 75 blinks by tracking how many stones carry each number.
+fn part2_a(numbers: &[usize]) -> usize {
+    let mut map = HashMap::new();
+    for &num in numbers {
+        match map.get(&num) {
+            Some(count) => {
+                map.insert(num, count + 1);
+            }
+            None => {
+                map.insert(num, 1);
+            }
+        }
+    }
+
+    for _ in 0..75 {
+        map = blink_map(map);
+    }
+
+    map.values().sum()
+}
 
-    //region Part 1
-    println!("=== Part 1 ===");
+pub struct Day11;
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let line = reader.lines().next().unwrap()?;
-        let numbers: Vec<_> = line.split(' ').map(|x| x.parse().unwrap()).collect();
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let line = reader.lines().next().context("Empty input")??;
+        let numbers = line
+            .split(' ')
+            .map(|x| x.parse())
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(numbers)
+    }
 
+    fn part1(&self, numbers: &Self::Input) -> Result<usize> {
         let mut count = 0;
-        for num in numbers {
+        for &num in numbers {
             let mut stones = vec![num];
             for _ in 0..25 {
                 stones = blink_vec(&stones);
@@ -162,20 +186,7 @@ fn main() -> Result<()> {
         Ok(count)
     }
 
-    assert_eq!(55312, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let line = reader.lines().next().unwrap()?;
-        let mut numbers: Vec<_> = line.split(' ').map(|x| x.parse().unwrap()).collect();
-
+    fn part2(&self, numbers: &Self::Input) -> Result<usize> {
         let num_count = numbers.len();
 
         let num_threads: usize = if num_count < MAX_NUM_THREADS {
@@ -207,7 +218,7 @@ fn main() -> Result<()> {
                     move || {
                         let mut cache = HashMap::new();
                         let mut count = 0;
-                        for (_i, num) in queue.iter().enumerate() {
+                        for num in queue.iter() {
                             // println!(
                             //     "Thread {:?}, blinking number {}/{}: {}",
                             //     thread::current().id(),
@@ -232,42 +243,17 @@ fn main() -> Result<()> {
         Ok(count)
     }
 
-    println!(
-        "Part 2 on test input: {}",
-        part2(BufReader::new(TEST.as_bytes()))?
-    );
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    fn part2_a<R: BufRead>(reader: R)-> Result<usize>{
-        let line = reader.lines().next().unwrap()?;
-        let numbers: Vec<_> = line.split(' ').map(|x| x.parse().unwrap()).collect();
-
-        let mut map = HashMap::new();
-        for num in numbers{
-            match map.get(&num) {
-                Some(count) => {
-                    map.insert(num, count + 1);
-                }
-                None => { map.insert(num, 1); }
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(55312, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                // The puzzle gives no answer for 75 blinks, so cross-check the two solvers instead.
+                let numbers = self.parse(&mut TEST.as_bytes())?;
+                ensure_eq!(part2_a(&numbers), self.part2(&numbers)?);
             }
         }
-
-        for _ in 0..75{
-            map = blink_map(map);
-        }
-
-        let sum = map.iter().map(move |(n,c)| {*c}).sum();
-
-        Ok(sum)
+        Ok(())
     }
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2_a(input_file)?);
-    println!("Result = {}", result);
-
-    Ok(())
 }
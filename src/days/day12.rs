@@ -1,14 +1,8 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-const DAY: &str = "12";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use std::io::BufRead;
 
 const TEST: &str = "\
 RRRRIICCFF
@@ -138,6 +132,7 @@ impl Region {
         false
     }
 
+    #[allow(dead_code)]
     fn print_in_field(&self, field_size: (usize, usize)) {
         let mut char_field = vec![vec!['.'; field_size.1]; field_size.0];
         for pos in &self.fields {
@@ -162,13 +157,11 @@ impl Region {
         }
 
         for field in &self.fields {
-            if field.0 > 1 && other.is_inside((field.0 - 1, field.1)) {
-                return true;
-            } else if field.0 < self.field_size.0 - 1 && other.is_inside((field.0 + 1, field.1)) {
-                return true;
-            } else if field.1 > 0 && other.is_inside((field.0, field.1 - 1)) {
-                return true;
-            } else if field.1 < self.field_size.1 - 1 && other.is_inside((field.0, field.1 + 1)) {
+            if (field.0 > 0 && other.is_inside((field.0 - 1, field.1)))
+                || (field.0 < self.field_size.0 - 1 && other.is_inside((field.0 + 1, field.1)))
+                || (field.1 > 0 && other.is_inside((field.0, field.1 - 1)))
+                || (field.1 < self.field_size.1 - 1 && other.is_inside((field.0, field.1 + 1)))
+            {
                 return true;
             }
         }
@@ -185,7 +178,7 @@ impl Region {
     }
 }
 
-fn build_regions(field: &Vec<Vec<char>>) -> Vec<Region> {
+fn build_regions(field: &[Vec<char>]) -> Vec<Region> {
     let field_size = (field.len(), field[0].len());
 
     let mut plant_pos = HashMap::new();
@@ -207,7 +200,7 @@ fn build_regions(field: &Vec<Vec<char>>) -> Vec<Region> {
     for (plant, positions) in plant_pos {
         let mut plant_regions = Vec::new();
         'pos_loop: for pos in positions {
-            if plant_regions.len() == 0 {
+            if plant_regions.is_empty() {
                 plant_regions.push(Region::from_pos(plant, pos, field_size));
                 continue;
             }
@@ -246,18 +239,25 @@ fn build_regions(field: &Vec<Vec<char>>) -> Vec<Region> {
     regions
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+pub struct Day12;
 
-    //region Part 1
-    println!("=== Part 1 ===");
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let field: Vec<Vec<char>> = reader
-            .lines()
-            .map(|x| x.unwrap().chars().collect())
-            .collect();
-        let regions = build_regions(&field);
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        Ok(parse::char_rows(&input, "a plant letter", |c| {
+            c.is_ascii_uppercase()
+        })?)
+    }
+
+    fn part1(&self, field: &Self::Input) -> Result<usize> {
+        let regions = build_regions(field);
 
         // println!("\n");
         // let field_rows = field.len();
@@ -272,28 +272,23 @@ fn main() -> Result<()> {
         Ok(answer)
     }
 
-    assert_eq!(1930, part1(BufReader::new(TEST.as_bytes()))?);
-    assert_eq!(140, part1(BufReader::new(TEST2.as_bytes()))?);
-    assert_eq!(772, part1(BufReader::new(TEST3.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    // println!("\n=== Part 2 ===");
-    //
-    // fn part2<R: BufRead>(reader: R) -> Result<usize> {
-    //     Ok(0)
-    // }
-    //
-    // assert_eq!(0, part2(BufReader::new(TEST.as_bytes()))?);
-    //
-    // let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    // let result = time_snippet!(part2(input_file)?);
-    // println!("Result = {}", result);
-    //endregion
-
-    Ok(())
+    fn part2(&self, _field: &Self::Input) -> Result<usize> {
+        bail!("Not solved yet")
+    }
+
+    fn has_part2(&self) -> bool {
+        false
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(1930, self.part1_str(TEST)?);
+                ensure_eq!(140, self.part1_str(TEST2)?);
+                ensure_eq!(772, self.part1_str(TEST3)?);
+            }
+            Part::Two => {}
+        }
+        Ok(())
+    }
 }
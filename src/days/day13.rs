@@ -0,0 +1,133 @@
+use crate::*;
+use anyhow::*;
+use std::io::BufRead;
+
+const TEST: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    button_a: (usize, usize),
+    button_b: (usize, usize),
+    prize: (usize, usize),
+}
+
+fn calc_cost(claw_machine: &ClawMachine) -> Option<usize> {
+    let a0 = claw_machine.button_a.0 as f64;
+    let a1 = claw_machine.button_a.1 as f64;
+    let b0 = claw_machine.button_b.0 as f64;
+    let b1 = claw_machine.button_b.1 as f64;
+    let p0 = claw_machine.prize.0 as f64;
+    let p1 = claw_machine.prize.1 as f64;
+
+    let s = (p1 - (a1 * p0) / a0) / ((a1 * b0) / a0 - b1);
+    let x = (s * b0 + p0, s * b1 + p1);
+    let diff_b = (p0 - x.0, p1 - x.1);
+
+    let num_a = (x.0 / a0).round() as usize;
+    let num_b = (diff_b.0 / b0).round() as usize;
+
+    let price_test = (
+        claw_machine.button_a.0 * num_a + claw_machine.button_b.0 * num_b,
+        claw_machine.button_a.1 * num_a + claw_machine.button_b.1 * num_b,
+    );
+    if price_test != claw_machine.prize {
+        return None;
+    }
+
+    let costs = 3 * num_a + num_b;
+    Some(costs)
+}
+
+fn parse_claw_machines(reader: &mut dyn BufRead) -> Result<Vec<ClawMachine>> {
+    let input = std::io::read_to_string(reader)?;
+
+    let mut claw_machines = Vec::new();
+    for section in parse::sections(&input) {
+        let [a, b, p] = section.lines[..] else {
+            bail!(section.lines[0].error("Expected button A, button B and prize lines"));
+        };
+        let [a_x, a_y] = a.record("Button A: X+{}, Y+{}")?;
+        let [b_x, b_y] = b.record("Button B: X+{}, Y+{}")?;
+        let [p_x, p_y] = p.record("Prize: X={}, Y={}")?;
+
+        claw_machines.push(ClawMachine {
+            button_a: (a_x, a_y),
+            button_b: (b_x, b_y),
+            prize: (p_x, p_y),
+        });
+    }
+
+    Ok(claw_machines)
+}
+
+const PRIZE_OFFSET: usize = 10000000000000;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Vec<ClawMachine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        parse_claw_machines(reader)
+    }
+
+    fn part1(&self, claw_machines: &Self::Input) -> Result<usize> {
+        let mut total_cost = 0;
+        for claw_machine in claw_machines {
+            if let Some(cost) = calc_cost(claw_machine) {
+                total_cost += cost;
+            }
+        }
+
+        Ok(total_cost)
+    }
+
+    fn part2(&self, claw_machines: &Self::Input) -> Result<usize> {
+        let mut total_cost = 0;
+        for claw_machine in claw_machines {
+            let claw_machine = ClawMachine {
+                prize: (
+                    claw_machine.prize.0 + PRIZE_OFFSET,
+                    claw_machine.prize.1 + PRIZE_OFFSET,
+                ),
+                ..claw_machine.clone()
+            };
+            if let Some(cost) = calc_cost(&claw_machine) {
+                total_cost += cost;
+            }
+        }
+
+        Ok(total_cost)
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(480, self.part1_str(TEST)?);
+            }
+            Part::Two => {}
+        }
+        Ok(())
+    }
+}
@@ -1,16 +1,7 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use image::{DynamicImage, RgbImage};
-use regex::Regex;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
-use std::thread;
-use std::time::Duration;
-
-const DAY: &str = "14";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use image::DynamicImage;
+use std::io::BufRead;
 
 const TEST: &str = "\
 p=0,4 v=3,-3
@@ -32,7 +23,7 @@ const TEST_SIZE: (isize, isize) = (11, 7);
 const ACTUAL_SIZE: (isize, isize) = (101, 103);
 
 #[derive(Debug)]
-struct Robot {
+pub struct Robot {
     position: (isize, isize),
     velocity: (isize, isize),
     limits: (isize, isize),
@@ -47,12 +38,10 @@ impl Robot {
         }
     }
 
-    fn from_str(s: &str, limits: (isize, isize)) -> Self {
-        let re = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
-        let caps = re.captures(s).unwrap();
-        let position = (caps[1].parse().unwrap(), caps[2].parse().unwrap());
-        let velocity = (caps[3].parse().unwrap(), caps[4].parse().unwrap());
-        Self::new(position, velocity, limits)
+    /// Parses a line like `p=0,4 v=3,-3`.
+    fn from_line(line: &parse::Line, limits: (isize, isize)) -> Result<Self> {
+        let [p_x, p_y, v_x, v_y] = line.record("p={},{} v={},{}")?;
+        Ok(Self::new((p_x, p_y), (v_x, v_y), limits))
     }
 
     fn position_at(&self, time: isize) -> (isize, isize) {
@@ -86,6 +75,7 @@ fn quadrant_of_pos(pos: (isize, isize), limits: (isize, isize)) -> usize {
     }
 }
 
+#[allow(dead_code)]
 fn print_at_time(robots: &[Robot], time: isize, limits: (isize, isize)) {
     let mut field = vec![vec![' '; limits.0 as usize]; limits.1 as usize];
     for robot in robots {
@@ -122,60 +112,80 @@ fn save_img_at_time(robots: &[Robot], time: isize, limits: (isize, isize)) {
     let _ = img.save(path);
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+pub struct Day14 {
+    /// Width and height of the room the robots move in.
+    pub field_size: (isize, isize),
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            field_size: ACTUAL_SIZE,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    type Input = Vec<Robot>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    //region Part 1
-    println!("=== Part 1 ===");
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let mut robots = vec![];
+        for line in parse::lines(&input) {
+            robots.push(Robot::from_line(&line, self.field_size)?);
+        }
+        if robots.is_empty() {
+            bail!(ParseError::line(1, "", "No robots in the input"));
+        }
+        Ok(robots)
+    }
 
-    fn part1<R: BufRead>(reader: R, field_size: (isize, isize)) -> Result<usize> {
+    fn part1(&self, robots: &Self::Input) -> Result<usize> {
         let mut answers: [usize; 5] = [0, 0, 0, 0, 0];
-        for line in reader.lines() {
-            let line = line?;
-            let robot = Robot::from_str(&line, field_size);
+        for robot in robots {
             let pos_100 = robot.position_at(100);
-            answers[quadrant_of_pos(pos_100, field_size)] += 1;
+            answers[quadrant_of_pos(pos_100, self.field_size)] += 1;
         }
 
+        // No robot in any quadrant leaves nothing to multiply, the safety factor is then 0
         let answer: usize = answers[1..]
             .iter()
             .filter(|x| (**x) != 0)
-            .map(|x1| *x1)
+            .copied()
             .reduce(|a, b| a * b)
-            .unwrap();
+            .unwrap_or(0);
         Ok(answer)
     }
 
-    assert_eq!(12, part1(BufReader::new(TEST.as_bytes()), TEST_SIZE)?);
-    println!("Test passed");
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file, ACTUAL_SIZE)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(reader: R, field_size: (isize, isize)) -> Result<usize> {
-        let mut robots: Vec<Robot> = vec![];
-        for line in reader.lines() {
-            let line = line?;
-            let robot = Robot::from_str(&line, field_size);
-            robots.push(robot);
-        }
-
+    fn part2(&self, robots: &Self::Input) -> Result<usize> {
         for time in 0..10000 {
-            save_img_at_time(&robots, time, field_size);
+            save_img_at_time(robots, time, self.field_size);
         }
         println!("Alle images saved to output/14/ have fun searching!");
         Ok(0)
     }
 
+    /// Part 2 is found by looking through the images written by `part2`, which has no answer
+    /// to print, time or check.
+    fn has_part2(&self) -> bool {
+        false
+    }
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    time_snippet!(part2(input_file, ACTUAL_SIZE)?);
-    //endregion
-
-    Ok(())
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                let example = Day14 {
+                    field_size: TEST_SIZE,
+                };
+                ensure_eq!(12, example.part1_str(TEST)?);
+            }
+            Part::Two => {}
+        }
+        Ok(())
+    }
 }
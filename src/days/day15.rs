@@ -1,13 +1,6 @@
-use adv_code_2024::*;
+use crate::*;
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use itertools::Itertools;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-const DAY: &str = "15";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+use std::io::BufRead;
 
 const TEST_1: &str = "\
 ##########
@@ -55,13 +48,13 @@ enum Cell {
 }
 
 impl Cell {
-    fn from_char(c: char, x: usize, y: usize) -> Self {
+    fn from_char(c: char, x: usize, y: usize) -> Option<Self> {
         match c {
-            '#' => Cell::Wall,
-            '.' => Cell::Empty,
-            '@' => Cell::Robot,
-            'O' => Cell::Box(x, y),
-            _ => panic!("Invalid cell type: {}", c),
+            '#' => Some(Cell::Wall),
+            '.' => Some(Cell::Empty),
+            '@' => Some(Cell::Robot),
+            'O' => Some(Cell::Box(x, y)),
+            _ => None,
         }
     }
 
@@ -73,36 +66,39 @@ impl Cell {
     }
 }
 
-struct Field {
+#[derive(Clone)]
+pub struct Field {
     size: (usize, usize),
     cells: Vec<Vec<Cell>>,
     robot_position: (usize, usize),
 }
 
+// A failed push must not fall through to the `Cell::Empty` arms, so the ifs stay in the arms.
+#[allow(clippy::collapsible_match)]
 impl Field {
-    fn from_str(input: &str) -> Self {
+    fn from_section(section: &parse::Section) -> Result<Self> {
         let mut cells = Vec::new();
         let mut robot_position = None;
         let mut size = (0, 0);
-        for (y, line) in input.lines().enumerate() {
+        for (y, line) in section.lines.iter().enumerate() {
             let mut row = Vec::new();
-            for (x, c) in line.chars().enumerate() {
-                let cell = Cell::from_char(c, x, y);
-                match cell {
-                    Cell::Robot => robot_position = Some((x, y)),
-                    _ => {}
+            for (x, (offset, c)) in line.text.char_indices().enumerate() {
+                let cell = Cell::from_char(c, x, y)
+                    .ok_or_else(|| line.error_at(offset, &c.to_string(), "Invalid cell type"))?;
+                if let Cell::Robot = cell {
+                    robot_position = Some((x, y))
                 }
                 row.push(cell);
             }
             size.1 = y + 1;
-            size.0 = line.len();
+            size.0 = line.text.len();
             cells.push(row);
         }
-        Field {
+        Ok(Field {
             size,
             cells,
-            robot_position: robot_position.unwrap(),
-        }
+            robot_position: robot_position.context("No robot '@' in the warehouse")?,
+        })
     }
 
     fn enlarge(&mut self) {
@@ -167,12 +163,9 @@ impl Field {
         }
 
         for cell in self.cells.iter().flatten() {
-            match cell {
-                Cell::Box(x, y) => {
-                    out[*y][*x] = '[';
-                    out[*y][*x + 1] = ']';
-                }
-                _ => {}
+            if let Cell::Box(x, y) = cell {
+                out[*y][*x] = '[';
+                out[*y][*x + 1] = ']';
             }
         }
 
@@ -188,20 +181,18 @@ impl Field {
             return;
         }
 
-        match self.cells[ny as usize][nx as usize] {
-            Cell::Wall => return,
+        let next_cell = self.cells[ny as usize][nx as usize];
+        match next_cell {
+            Cell::Wall => (),
             Cell::Empty => {
                 self.robot_position = (nx as usize, ny as usize);
                 self.cells[y][x] = Cell::Empty;
                 self.cells[self.robot_position.1][self.robot_position.0] = Cell::Robot;
-                return;
             }
-            Cell::Box(_, _) => {
-                if self.push((nx as usize, ny as usize), direction) {
-                    self.robot_position = (nx as usize, ny as usize);
-                    self.cells[y][x] = Cell::Empty;
-                    self.cells[self.robot_position.1][self.robot_position.0] = Cell::Robot;
-                }
+            Cell::Box(_, _) if self.push((nx as usize, ny as usize), direction) => {
+                self.robot_position = (nx as usize, ny as usize);
+                self.cells[y][x] = Cell::Empty;
+                self.cells[self.robot_position.1][self.robot_position.0] = Cell::Robot;
             }
             _ => {}
         }
@@ -233,9 +224,7 @@ impl Field {
                     self.cells[ny][nx] = Cell::Robot;
                     self.cells[y][x] = Cell::Empty;
                 }
-                _ => {
-                    return;
-                }
+                _ => {}
             },
             Direction::Right => match self.cells[ny][nx] {
                 Cell::Empty => {
@@ -250,9 +239,7 @@ impl Field {
                         self.cells[y][x] = Cell::Empty;
                     }
                 }
-                _ => {
-                    return;
-                }
+                _ => {}
             },
             Direction::Up | Direction::Down => match (self.cells[lny][lnx], self.cells[ny][nx]) {
                 (_, Cell::Box(_, _)) => {
@@ -274,9 +261,7 @@ impl Field {
                     self.cells[ny][nx] = Cell::Robot;
                     self.cells[y][x] = Cell::Empty;
                 }
-                _ => {
-                    return;
-                }
+                _ => {}
             },
         }
     }
@@ -292,18 +277,15 @@ impl Field {
 
         let (nx, ny) = (nx as usize, ny as usize);
 
-        match self.cells[ny][nx] {
+        let next_cell = self.cells[ny][nx];
+        match next_cell {
             Cell::Empty => {
                 self.cells[ny][nx] = Cell::Box(nx, ny);
                 true
             }
-            Cell::Box(_, _) => {
-                if self.push((nx, ny), direction) {
-                    self.cells[ny][nx] = Cell::Box(nx, ny);
-                    true
-                } else {
-                    false
-                }
+            Cell::Box(_, _) if self.push((nx, ny), direction) => {
+                self.cells[ny][nx] = Cell::Box(nx, ny);
+                true
             }
             _ => false,
         }
@@ -425,71 +407,39 @@ impl Field {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn from_char(c: char) -> Self {
-        match c {
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            _ => panic!("Invalid direction: {}", c),
-        }
-    }
-
-    #[allow(dead_code)]
-    fn as_char(&self) -> char {
-        match self {
-            Direction::Up => '^',
-            Direction::Down => 'v',
-            Direction::Left => '<',
-            Direction::Right => '>',
+fn parse_path(section: &parse::Section) -> Result<Vec<Direction>> {
+    let mut path = Vec::new();
+    for line in &section.lines {
+        for (offset, c) in line.text.trim_end().char_indices() {
+            let direction = Direction::from_char(c)
+                .map_err(|_| line.error_at(offset, &c.to_string(), "Invalid move"))?;
+            path.push(direction);
         }
     }
-
-    fn as_coords(&self) -> (isize, isize) {
-        match self {
-            Direction::Up => (0, -1),
-            Direction::Down => (0, 1),
-            Direction::Left => (-1, 0),
-            Direction::Right => (1, 0),
-        }
-    }
-
-    fn parse_path(input: &str) -> Vec<Direction> {
-        input
-            .lines()
-            .join("")
-            .trim_end()
-            .chars()
-            .map(Direction::from_char)
-            .collect()
-    }
+    Ok(path)
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+pub struct Day15;
 
-    //region Part 1
-    println!("=== Part 1 ===");
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
 
-    fn part1<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader.read_to_string(&mut input)?;
+    /// The warehouse and the robot's moves.
+    type Input = (Field, Vec<Direction>);
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-        let input_split = input.split("\n\n").collect::<Vec<&str>>();
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let input = std::io::read_to_string(reader)?;
+        let [map, moves] = parse::n_sections(&input)?;
+        Ok((Field::from_section(&map)?, parse_path(&moves)?))
+    }
 
-        let mut field = Field::from_str(input_split[0]);
-        let path = Direction::parse_path(input_split[1]);
+    fn part1(&self, (field, path): &Self::Input) -> Result<usize> {
+        let mut field = field.clone();
 
-        for direction in path {
+        for &direction in path {
             field.robot_push(direction);
         }
 
@@ -503,30 +453,13 @@ fn main() -> Result<()> {
         Ok(coordinate_sum)
     }
 
-    assert_eq!(2028, part1(BufReader::new(TEST_2.as_bytes()))?);
-    assert_eq!(10092, part1(BufReader::new(TEST_1.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    //region Part 2
-    println!("\n=== Part 2 ===");
-
-    fn part2<R: BufRead>(mut reader: R) -> Result<usize> {
-        let mut input = String::new();
-        reader.read_to_string(&mut input)?;
-
-        let input_split = input.split("\n\n").collect::<Vec<&str>>();
-
-        let mut field = Field::from_str(input_split[0]);
-        let path = Direction::parse_path(input_split[1]);
+    fn part2(&self, (field, path): &Self::Input) -> Result<usize> {
+        let mut field = field.clone();
 
         field.enlarge();
 
-        for direction in &path {
-            field.robot_push_wide(*direction);
+        for &direction in path {
+            field.robot_push_wide(direction);
         }
 
         let coordinate_sum = field
@@ -539,12 +472,16 @@ fn main() -> Result<()> {
         Ok(coordinate_sum)
     }
 
-    assert_eq!(9021, part2(BufReader::new(TEST_1.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-    //endregion
-
-    Ok(())
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(2028, self.part1_str(TEST_2)?);
+                ensure_eq!(10092, self.part1_str(TEST_1)?);
+            }
+            Part::Two => {
+                ensure_eq!(9021, self.part2_str(TEST_1)?);
+            }
+        }
+        Ok(())
+    }
 }
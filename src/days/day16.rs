@@ -0,0 +1,298 @@
+use crate::search::SearchResult;
+use crate::*;
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+const TEST_1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+const TEST_2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+#[derive(Clone, Copy, Debug)]
+pub enum Field {
+    Free,
+    Wall,
+    Exit,
+    Start,
+}
+
+/// What it costs the reindeer to move through the maze.
+///
+/// Turning happens on the spot, so a move that also changes the heading costs the turn and
+/// the step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostModel {
+    /// Cost of one step forward.
+    pub step: usize,
+    /// Cost of turning by 90°.
+    pub turn: usize,
+    /// Cost of turning by 180°, `None` if the reindeer cannot turn around.
+    pub reverse: Option<usize>,
+    /// Heading at the start tile.
+    pub start: Direction,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            step: 1,
+            turn: 1000,
+            reverse: Some(2000),
+            start: Direction::Right,
+        }
+    }
+}
+
+impl CostModel {
+    /// Cost of stepping in direction `to` while facing `from`, `None` if that is not allowed.
+    fn cost_move(&self, from: Direction, to: Direction) -> Option<usize> {
+        let turn = if from == to {
+            0
+        } else if from.opposite() == to {
+            self.reverse?
+        } else {
+            self.turn
+        };
+        Some(turn + self.step)
+    }
+
+    /// Sets one of the costs: `step`, `turn`, `reverse` (`none` to forbid it) or `start`
+    /// (`^`, `>`, `v`, `<` or `N`, `E`, `S`, `W`).
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let cost = |value: &str| {
+            value
+                .parse::<usize>()
+                .with_context(|| format!("Invalid cost for {}: {}", name, value))
+        };
+        match name {
+            "step" => self.step = cost(value)?,
+            "turn" => self.turn = cost(value)?,
+            "reverse" if value == "none" => self.reverse = None,
+            "reverse" => self.reverse = Some(cost(value)?),
+            "start" => {
+                let mut chars = value.chars();
+                self.start = match (chars.next(), chars.next()) {
+                    (Some(c), None) => Direction::from_char(c)?,
+                    _ => bail!("Invalid direction: {}", value),
+                };
+            }
+            _ => bail!(
+                "Day 16 has no option {}, expected step, turn, reverse or start",
+                name
+            ),
+        }
+        Ok(())
+    }
+}
+
+fn parse_maze<R: BufRead>(reader: R) -> Result<Vec<Vec<Field>>> {
+    let mut maze = Vec::new();
+    for (y, line) in reader.lines().enumerate() {
+        let line = line?;
+        let mut row = Vec::new();
+        for (x, c) in line.trim_end().chars().enumerate() {
+            row.push(match c {
+                '#' => Field::Wall,
+                'E' => Field::Exit,
+                'S' => Field::Start,
+                '.' => Field::Free,
+                _ => bail!(ParseError::new(y + 1, x + 1, c, "Invalid maze field")),
+            });
+        }
+        maze.push(row);
+    }
+    Ok(maze)
+}
+
+fn find_start(maze: &[Vec<Field>]) -> Result<(usize, usize)> {
+    for (y, row) in maze.iter().enumerate() {
+        for (x, field) in row.iter().enumerate() {
+            if let Field::Start = field {
+                return Ok((x, y));
+            }
+        }
+    }
+    bail!("No start 'S' in the maze")
+}
+
+/// The reindeer at a position, facing a direction.
+type State = (Point, Direction);
+
+fn field_at(maze: &[Vec<Field>], pos: Point) -> Option<Field> {
+    let (x, y) = pos.to_usize()?;
+    maze.get(y)?.get(x).copied()
+}
+
+/// All cheapest ways from the start to the exit.
+fn search_maze(maze: &[Vec<Field>], costs: &CostModel) -> Result<SearchResult<State>> {
+    let start = Point::from(find_start(maze)?);
+    Ok(search::dijkstra(
+        [(start, costs.start)],
+        |&(pos, direction)| {
+            Direction::ALL.into_iter().filter_map(move |next| {
+                let next_pos = pos.step(next);
+                match field_at(maze, next_pos)? {
+                    Field::Wall => None,
+                    _ => Some(((next_pos, next), costs.cost_move(direction, next)?)),
+                }
+            })
+        },
+        |&(pos, _)| matches!(field_at(maze, pos), Some(Field::Exit)),
+    ))
+}
+
+/// The tiles on at least one of the cheapest paths from the start to the exit.
+pub fn best_tiles(maze: &[Vec<Field>], costs: &CostModel) -> Result<HashSet<Point>> {
+    let result = search_maze(maze, costs)?;
+    ensure!(!result.goals().is_empty(), "No path to the exit");
+    let states = result.on_optimal_paths(result.goals());
+    Ok(states.into_iter().map(|(pos, _)| pos).collect())
+}
+
+/// The maze with the given `tiles` marked `O`, like in the puzzle description.
+pub fn render_tiles(maze: &[Vec<Field>], tiles: &HashSet<Point>) -> String {
+    let mut out = String::new();
+    for (y, row) in maze.iter().enumerate() {
+        for (x, field) in row.iter().enumerate() {
+            out.push(match field {
+                _ if tiles.contains(&Point::from_usize(x, y)) => 'O',
+                Field::Free => '.',
+                Field::Wall => '#',
+                Field::Exit => 'E',
+                Field::Start => 'S',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[derive(Default)]
+pub struct Day16 {
+    pub costs: CostModel,
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    type Input = Vec<Vec<Field>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        parse_maze(reader)
+    }
+
+    fn part1(&self, maze: &Self::Input) -> Result<usize> {
+        search_maze(maze, &self.costs)?
+            .goal_cost()
+            .context("No path to the exit")
+    }
+
+    fn part2(&self, maze: &Self::Input) -> Result<usize> {
+        Ok(best_tiles(maze, &self.costs)?.len())
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        // The expected results are for the costs of the puzzle
+        let day = Day16::default();
+        match part {
+            Part::One => {
+                ensure_eq!(7036, day.part1_str(TEST_1)?);
+                ensure_eq!(11048, day.part1_str(TEST_2)?);
+            }
+            Part::Two => {
+                ensure_eq!(45, day.part2_str(TEST_1)?);
+                ensure_eq!(64, day.part2_str(TEST_2)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn set_option(&mut self, name: &str, value: &str) -> Result<()> {
+        self.costs.set(name, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_best_tiles() {
+        let maze = Day16::default().parse(&mut TEST_1.as_bytes()).unwrap();
+        let tiles = best_tiles(&maze, &CostModel::default()).unwrap();
+        let expected = "\
+###############
+#.......#....O#
+#.#.###.#.###O#
+#.....#.#...#O#
+#.###.#####.#O#
+#.#.#.......#O#
+#.#.#####.###O#
+#..OOOOOOOOO#O#
+###O#O#####O#O#
+#OOO#O....#O#O#
+#O#O#O###.#O#O#
+#OOOOO#...#O#O#
+#O###.#.#.#O#O#
+#O..#.....#OOO#
+###############
+";
+        assert_eq!(expected, render_tiles(&maze, &tiles));
+    }
+
+    #[test]
+    fn cost_model() {
+        let cost = |options: &[(&str, &str)]| {
+            let mut day = Day16::default();
+            for (name, value) in options {
+                Solution::set_option(&mut day, name, value).unwrap();
+            }
+            day.part1_str(TEST_1).unwrap()
+        };
+        assert_eq!(7036, cost(&[]));
+        assert_eq!(28, cost(&[("turn", "0"), ("reverse", "0")]));
+        assert_eq!(6036, cost(&[("start", "^")]));
+        assert_eq!(7036, cost(&[("start", "<"), ("reverse", "none")]));
+
+        let mut costs = CostModel::default();
+        assert!(costs.set("turn", "-1").is_err());
+        assert!(costs.set("start", "up").is_err());
+        assert!(costs.set("diagonal", "1").is_err());
+    }
+}
@@ -0,0 +1,1344 @@
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::ops::Range;
+
+const TEST: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+const TEST_2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+/// The eight instructions of the 3-bit computer, with their opcodes as discriminants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv = 0,
+    Bxl = 1,
+    Bst = 2,
+    Jnz = 3,
+    Bxc = 4,
+    Out = 5,
+    Bdv = 6,
+    Cdv = 7,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    pub fn from_code(code: u128) -> Option<Self> {
+        Self::ALL.get(usize::try_from(code).ok()?).copied()
+    }
+
+    pub fn code(self) -> u128 {
+        self as u128
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// Whether the operand is a combo operand; `bxl` and `jnz` take literals and `bxc`
+    /// ignores its operand.
+    pub fn takes_combo(self) -> bool {
+        !matches!(self, Opcode::Bxl | Opcode::Jnz | Opcode::Bxc)
+    }
+}
+
+/// A combo operand as written in listings: `0` to `3`, or the register `A`, `B` or `C`.
+fn combo_name(operand: u128) -> String {
+    match operand {
+        0..=3 => operand.to_string(),
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => format!("?{}", operand),
+    }
+}
+
+/// An opcode with its operand, displayed as mnemonic like `bst A`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u128,
+}
+
+impl Instruction {
+    /// The operand as written after the mnemonic.
+    fn operand_name(&self) -> String {
+        match self.opcode {
+            Opcode::Bxc => "-".to_string(),
+            opcode if opcode.takes_combo() => combo_name(self.operand),
+            _ => self.operand.to_string(),
+        }
+    }
+
+    /// What the instruction does, e.g. `C = A / 2**B` for `cdv B`.
+    pub fn pseudo_code(&self) -> String {
+        let operand = self.operand_name();
+        match self.opcode {
+            Opcode::Adv => format!("A = A / 2**{}", operand),
+            Opcode::Bxl => format!("B = B ^ {}", operand),
+            Opcode::Bst => format!("B = {} % 8", operand),
+            Opcode::Jnz => format!("if A != 0: goto {}", operand),
+            Opcode::Bxc => "B = B ^ C".to_string(),
+            Opcode::Out => format!("print({} % 8)", operand),
+            Opcode::Bdv => format!("B = A / 2**{}", operand),
+            Opcode::Cdv => format!("C = A / 2**{}", operand),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.opcode.mnemonic(), self.operand_name())
+    }
+}
+
+/// A value computed by the program, in terms of the registers at the start.
+///
+/// Built through [`Expr::xor`], [`Expr::mod8`] and [`Expr::divide`], which fold constants, so
+/// `((A % 8) ^ 5) ^ 6` becomes `(A % 8) ^ 3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(u128),
+    /// Register `'A'`, `'B'` or `'C'` as it was at the start.
+    Register(char),
+    Xor(Box<Expr>, Box<Expr>),
+    Mod8(Box<Expr>),
+    /// The first value divided by two to the power of the second.
+    Div(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// `left ^ right`, with constants collected on the right.
+    pub fn xor(left: Expr, right: Expr) -> Expr {
+        match (left, right) {
+            (Expr::Const(l), Expr::Const(r)) => Expr::Const(l ^ r),
+            (Expr::Const(0), other) | (other, Expr::Const(0)) => other,
+            (Expr::Const(c), other) | (other, Expr::Const(c)) => match other {
+                Expr::Xor(inner, constant) => match *constant {
+                    Expr::Const(d) => Expr::xor(*inner, Expr::Const(c ^ d)),
+                    constant => Expr::Xor(
+                        Box::new(Expr::Xor(inner, Box::new(constant))),
+                        Box::new(Expr::Const(c)),
+                    ),
+                },
+                other => Expr::Xor(Box::new(other), Box::new(Expr::Const(c))),
+            },
+            (left, right) => Expr::Xor(Box::new(left), Box::new(right)),
+        }
+    }
+
+    /// `value % 8`, dropped when the value has three bits only anyway.
+    pub fn mod8(value: Expr) -> Expr {
+        match value {
+            Expr::Const(c) => Expr::Const(c % 8),
+            value if value.max_value().is_some_and(|max| max < 8) => value,
+            value => Expr::Mod8(Box::new(value)),
+        }
+    }
+
+    /// `value / 2**exponent`, with divisions by constants merged.
+    pub fn divide(value: Expr, exponent: Expr) -> Expr {
+        match (value, exponent) {
+            (value, Expr::Const(0)) => value,
+            (Expr::Const(v), Expr::Const(e)) => {
+                let shifted = u32::try_from(e).ok().and_then(|e| v.checked_shr(e));
+                Expr::Const(shifted.unwrap_or(0))
+            }
+            (Expr::Div(inner, first), Expr::Const(e)) => match *first {
+                Expr::Const(d) => Expr::divide(*inner, Expr::Const(d.saturating_add(e))),
+                first => Expr::Div(
+                    Box::new(Expr::Div(inner, Box::new(first))),
+                    Box::new(Expr::Const(e)),
+                ),
+            },
+            (value, exponent) => Expr::Div(Box::new(value), Box::new(exponent)),
+        }
+    }
+
+    /// The largest value the expression can have, `None` if that depends on a register.
+    pub fn max_value(&self) -> Option<u128> {
+        match self {
+            Expr::Const(c) => Some(*c),
+            Expr::Register(_) => None,
+            Expr::Xor(left, right) => {
+                let max = left.max_value()?.max(right.max_value()?);
+                Some(u128::MAX.checked_shr(max.leading_zeros()).unwrap_or(0))
+            }
+            Expr::Mod8(_) => Some(7),
+            Expr::Div(value, _) => value.max_value(),
+        }
+    }
+
+    /// The bits of the start value of A that the lowest `width` bits of the expression can
+    /// depend on, e.g. `3..6` for `(A / 2**3) % 8` and width 3, `None` if it does not depend on A.
+    ///
+    /// Dividing by `2**x` for an `x` below 8 shifts the range by up to 7 bits, so the range may
+    /// include bits that do not matter for every value of A.
+    pub fn a_bits(&self, width: u32) -> Option<Range<u32>> {
+        let union = |a: Option<Range<u32>>, b: Option<Range<u32>>| match (a, b) {
+            (Some(a), Some(b)) => Some(a.start.min(b.start)..a.end.max(b.end)),
+            (a, b) => a.or(b),
+        };
+        match self {
+            Expr::Const(_) | Expr::Register('B' | 'C') => None,
+            Expr::Register(_) => Some(0..width.min(u128::BITS)),
+            Expr::Xor(left, right) => union(left.a_bits(width), right.a_bits(width)),
+            Expr::Mod8(value) => value.a_bits(width.min(3)),
+            Expr::Div(value, exponent) => {
+                let exponent_bits = exponent.a_bits(u128::BITS);
+                let (low, high) = match exponent.max_value() {
+                    Some(max) => (exponent.min_shift(), max.min(u128::BITS as u128) as u32),
+                    None => (0, u128::BITS),
+                };
+                let shifted = value.a_bits(width).map(|bits| {
+                    (bits.start + low).min(u128::BITS)..(bits.end + high).min(u128::BITS)
+                });
+                union(shifted, exponent_bits)
+            }
+        }
+    }
+
+    /// The smallest exponent, only known for constants.
+    fn min_shift(&self) -> u32 {
+        match self {
+            Expr::Const(c) => (*c).min(u128::BITS as u128) as u32,
+            _ => 0,
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Const(c) => write!(f, "{}", c),
+            Expr::Register(register) => write!(f, "{}", register),
+            Expr::Xor(left, right) => write!(f, "({} ^ {})", Parens(left), Parens(right)),
+            Expr::Mod8(value) => write!(f, "{} % 8", Parens(value)),
+            Expr::Div(value, exponent) => write!(f, "{} / 2**{}", Parens(value), Parens(exponent)),
+        }
+    }
+}
+
+/// Writes an expression in parentheses unless it is a single value or already has them.
+struct Parens<'a>(&'a Expr);
+
+impl Display for Parens<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Expr::Mod8(_) | Expr::Div(..) => write!(f, "({})", self.0),
+            expr => write!(f, "{}", expr),
+        }
+    }
+}
+
+/// One pass through the loop of a program, run on symbolic registers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopBody {
+    /// The values output, in order.
+    pub outputs: Vec<Expr>,
+    /// Registers `[A, B, C]` when the loop jumps back or the program ends.
+    pub registers: [Expr; 3],
+    /// Address of the `jnz` that closes the loop, `None` if the program just ends.
+    pub jump: Option<usize>,
+}
+
+impl Display for LoopBody {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (idx, output) in self.outputs.iter().enumerate() {
+            write!(f, "out {}: {}", idx, output)?;
+            match output.a_bits(3) {
+                Some(bits) => writeln!(f, "  [A bits {}..{}]", bits.start, bits.end)?,
+                None => writeln!(f, "  [independent of A]")?,
+            }
+        }
+        for (name, register) in ['A', 'B', 'C'].iter().zip(&self.registers) {
+            if *register != Expr::Register(*name) {
+                writeln!(f, "{} = {}", name, register)?;
+            }
+        }
+        match self.jump {
+            Some(pc) => writeln!(f, "jnz at {}", pc),
+            None => writeln!(f, "end of program"),
+        }
+    }
+}
+
+/// Steps a [`Computer`] may execute unless configured otherwise, far more than any sensible
+/// program of the puzzle needs.
+pub const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+/// Why the computer stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Halt {
+    /// The program counter moved past the end of the program, the normal way to stop.
+    Finished,
+    /// The step budget was used up.
+    StepLimit(usize),
+    /// A jump arrived at `pc` with the same registers as before, so the program loops forever.
+    RepeatedState {
+        pc: usize,
+    },
+    InvalidOpcode {
+        pc: usize,
+        code: u128,
+    },
+    /// The reserved combo operand 7, or a value that does not fit in 3 bits.
+    InvalidOperand {
+        pc: usize,
+        operand: u128,
+    },
+    /// A division by `2**shift`, which does not fit in 128 bits.
+    ShiftOverflow {
+        pc: usize,
+        shift: u128,
+    },
+    /// The program ends with an opcode that has no operand.
+    TruncatedInstruction {
+        pc: usize,
+    },
+}
+
+impl Display for Halt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Halt::Finished => write!(f, "Program finished"),
+            Halt::StepLimit(limit) => write!(f, "Step limit of {} exceeded", limit),
+            Halt::RepeatedState { pc } => {
+                write!(f, "Endless loop, state at address {} repeated", pc)
+            }
+            Halt::InvalidOpcode { pc, code } => {
+                write!(f, "Invalid opcode {} at address {}", code, pc)
+            }
+            Halt::InvalidOperand { pc, operand } => {
+                write!(f, "Invalid combo operand {} at address {}", operand, pc)
+            }
+            Halt::ShiftOverflow { pc, shift } => {
+                write!(f, "Division by 2**{} overflows at address {}", shift, pc)
+            }
+            Halt::TruncatedInstruction { pc } => {
+                write!(f, "Opcode at address {} has no operand", pc)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Halt {}
+
+#[derive(Clone)]
+pub struct Computer {
+    a: u128,
+    b: u128,
+    c: u128,
+    pc: usize,
+    program: Vec<u128>,
+    output: Vec<u128>,
+    /// Instructions executed since the start.
+    steps: usize,
+    step_limit: usize,
+}
+
+impl Computer {
+    /// A computer with the registers `[A, B, C]` about to run `program`.
+    ///
+    /// Unlike the puzzle input, which is taken as it is, the program must be one the computer
+    /// can run as written: pairs of 3-bit numbers, without the reserved combo operand 7.
+    pub fn new(registers: [u128; 3], program: Vec<u128>) -> Result<Self> {
+        ensure!(
+            program.len().is_multiple_of(2),
+            "Program has odd length {}, the last opcode has no operand",
+            program.len()
+        );
+        if let Some((address, value)) = program.iter().enumerate().find(|(_, &v)| v > 7) {
+            bail!(
+                "Value {} at address {} does not fit in 3 bits",
+                value,
+                address
+            );
+        }
+        for (address, instruction) in Self::decode(&program) {
+            if let Some(instruction) = instruction {
+                ensure!(
+                    !instruction.opcode.takes_combo() || instruction.operand != 7,
+                    "Reserved combo operand 7 in `{}` at address {}",
+                    instruction,
+                    address
+                );
+            }
+        }
+
+        let [a, b, c] = registers;
+        Ok(Self {
+            a,
+            b,
+            c,
+            pc: 0,
+            program,
+            output: Vec::new(),
+            steps: 0,
+            step_limit: DEFAULT_STEP_LIMIT,
+        })
+    }
+
+    /// Assembles a program from mnemonics, one instruction per line:
+    ///
+    /// ```text
+    /// Register A: 2024   ; registers that are not given start at 0
+    /// loop: adv 1        ; a label names the address of the instruction after it
+    /// out A
+    /// jnz loop           ; jump targets are labels or addresses
+    /// ```
+    ///
+    /// Combo operands are `0` to `3` or a register, literals `0` to `7`, and the operand of
+    /// `bxc` may be left out or written as `-`. Comments start with `;` or `#`.
+    pub fn assemble(source: &str) -> Result<Self> {
+        let mut registers = [0; 3];
+        let mut labels = HashMap::new();
+        let mut instructions = Vec::new();
+        for line in parse::lines(source) {
+            let line = line.before(';').before('#').trim();
+            if line.text.is_empty() {
+                continue;
+            }
+            if line.text.starts_with("Register") {
+                let (name, value) = line.split_once(":")?;
+                let idx = match name.text {
+                    "Register A" => 0,
+                    "Register B" => 1,
+                    "Register C" => 2,
+                    _ => bail!(name.error("Expected register A, B or C")),
+                };
+                registers[idx] = value.trim().parse()?;
+                continue;
+            }
+
+            let mut code = line;
+            if line.text.contains(':') {
+                let (label, rest) = line.split_once(":")?;
+                let label = label.trim();
+                ensure!(
+                    !label.text.is_empty()
+                        && label.text.chars().all(|c| c.is_alphanumeric() || c == '_'),
+                    label.error("Invalid label")
+                );
+                let address = 2 * instructions.len();
+                if labels.insert(label.text, address).is_some() {
+                    bail!(label.error("Duplicate label"));
+                }
+                code = rest.trim();
+                if code.text.is_empty() {
+                    continue;
+                }
+            }
+
+            let (mnemonic, operand) = match code.text.find(char::is_whitespace) {
+                Some(idx) => {
+                    let (mnemonic, operand) = code.split_once(&code.text[idx..idx + 1])?;
+                    (mnemonic, Some(operand.trim()))
+                }
+                None => (code, None),
+            };
+            let opcode = Opcode::ALL
+                .into_iter()
+                .find(|opcode| opcode.mnemonic() == mnemonic.text)
+                .ok_or_else(|| mnemonic.error("Unknown instruction"))?;
+            instructions.push((opcode, mnemonic, operand));
+        }
+
+        let mut program = Vec::with_capacity(2 * instructions.len());
+        for (opcode, mnemonic, operand) in instructions {
+            let operand = match (opcode, operand) {
+                (Opcode::Bxc, None) => 0,
+                (Opcode::Bxc, Some(operand)) if operand.text == "-" => 0,
+                (_, None) => bail!(mnemonic.error("Missing operand")),
+                (Opcode::Jnz, Some(target)) if labels.contains_key(target.text) => {
+                    let address = labels[target.text] as u128;
+                    ensure!(
+                        address <= 7,
+                        target.error(format!("Address {} does not fit in 3 bits", address))
+                    );
+                    address
+                }
+                (Opcode::Jnz, Some(target)) if target.parse::<u128>().is_err() => {
+                    bail!(target.error("Unknown label"))
+                }
+                (opcode, Some(operand)) if opcode.takes_combo() => match operand.text {
+                    "A" => 4,
+                    "B" => 5,
+                    "C" => 6,
+                    "7" => bail!(operand.error("Combo operand 7 is reserved")),
+                    _ => match operand.parse()? {
+                        value @ 0..=3 => value,
+                        _ => bail!(operand.error("Combo operand must be 0 to 3, A, B or C")),
+                    },
+                },
+                (_, Some(operand)) => match operand.parse()? {
+                    value @ 0..=7 => value,
+                    _ => bail!(operand.error("Literal operand must be 0 to 7")),
+                },
+            };
+            program.push(opcode.code());
+            program.push(operand);
+        }
+
+        Self::new(registers, program)
+    }
+
+    fn from_reader(reader: &mut dyn BufRead) -> Result<Self> {
+        let input = std::io::read_to_string(reader)?;
+        let [registers, program] = parse::n_sections(&input)?;
+        let [a, b, c] = registers.lines[..] else {
+            bail!(registers.lines[0].error("Expected registers A, B and C"));
+        };
+        let [program] = program.lines[..] else {
+            bail!(program.lines[1].error("Expected the program on a single line"));
+        };
+
+        let [a] = a.record("Register A: {}")?;
+        let [b] = b.record("Register B: {}")?;
+        let [c] = c.record("Register C: {}")?;
+        let program = program.strip_prefix("Program: ")?.list(',')?;
+
+        Ok(Self {
+            a,
+            b,
+            c,
+            pc: 0,
+            program,
+            output: Vec::new(),
+            steps: 0,
+            step_limit: DEFAULT_STEP_LIMIT,
+        })
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        println!(
+            "A: {}, B: {}, C: {}, PC: {}, \n\tProgramm:\t{:?}\n\tOutput:\t\t{:?}",
+            self.a, self.b, self.c, self.pc, self.program, self.output
+        );
+    }
+
+    /// Runs the program until it halts.
+    ///
+    /// Besides the step limit, every taken jump is remembered with the registers at that point:
+    /// arriving at the same jump target with the same registers again means the program never
+    /// stops.
+    pub fn run(&mut self) -> std::result::Result<(), Halt> {
+        let mut seen = HashSet::new();
+        loop {
+            match self.step() {
+                Err(Halt::Finished) => return std::result::Result::Ok(()),
+                Err(halt) => return Err(halt),
+                std::result::Result::Ok(instruction) => {
+                    let jumped = instruction.opcode == Opcode::Jnz && self.a != 0;
+                    if jumped && !seen.insert((self.pc, self.a, self.b, self.c)) {
+                        return Err(Halt::RepeatedState { pc: self.pc });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Executes the instruction at the program counter and returns it.
+    ///
+    /// Fails with [`Halt::Finished`] once the program has run to its end, and with the reason
+    /// when the instruction cannot be executed; the computer is left unchanged then.
+    pub fn step(&mut self) -> std::result::Result<Instruction, Halt> {
+        let pc = self.pc;
+        let Some(&code) = self.program.get(pc) else {
+            return Err(Halt::Finished);
+        };
+        if self.steps >= self.step_limit {
+            return Err(Halt::StepLimit(self.step_limit));
+        }
+        let opcode = Opcode::from_code(code).ok_or(Halt::InvalidOpcode { pc, code })?;
+        let operand = *self
+            .program
+            .get(pc + 1)
+            .ok_or(Halt::TruncatedInstruction { pc })?;
+        let instruction = Instruction { opcode, operand };
+
+        match opcode {
+            Opcode::Adv => self.a = self.divide(operand)?,
+            Opcode::Bxl => self.b ^= operand,
+            Opcode::Bst => self.b = self.combo(operand)? % 8,
+            Opcode::Jnz if self.a != 0 => {
+                self.pc = operand as usize;
+                self.steps += 1;
+                return std::result::Result::Ok(instruction);
+            }
+            Opcode::Jnz => {}
+            Opcode::Bxc => self.b ^= self.c,
+            Opcode::Out => {
+                let value = self.combo(operand)? % 8;
+                self.output.push(value);
+            }
+            Opcode::Bdv => self.b = self.divide(operand)?,
+            Opcode::Cdv => self.c = self.divide(operand)?,
+        }
+        self.pc += 2;
+        self.steps += 1;
+        std::result::Result::Ok(instruction)
+    }
+
+    /// Limits how many instructions [`run`](Self::run) and [`step`](Self::step) execute in
+    /// total, [`DEFAULT_STEP_LIMIT`] unless set.
+    pub fn set_step_limit(&mut self, limit: usize) {
+        self.step_limit = limit;
+    }
+
+    /// Instructions executed so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The registers `[A, B, C]`.
+    pub fn registers(&self) -> [u128; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn output(&self) -> &[u128] {
+        &self.output
+    }
+
+    /// The instructions of the program by address, `None` for values that are no opcode.
+    ///
+    /// A trailing opcode without operand is left out, running it halts with
+    /// [`Halt::TruncatedInstruction`].
+    pub fn instructions(&self) -> Vec<(usize, Option<Instruction>)> {
+        Self::decode(&self.program)
+    }
+
+    fn decode(program: &[u128]) -> Vec<(usize, Option<Instruction>)> {
+        program
+            .chunks_exact(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let instruction = Opcode::from_code(pair[0]).map(|opcode| Instruction {
+                    opcode,
+                    operand: pair[1],
+                });
+                (2 * i, instruction)
+            })
+            .collect()
+    }
+
+    /// An annotated listing of the program, one instruction per line:
+    ///
+    /// ```text
+    /// >  0: 2,4 | bst A | B = A % 8
+    ///    2: 1,5 | bxl 5 | B = B ^ 5
+    /// ```
+    ///
+    /// Lines starting with `>` are targets of a `jnz`.
+    pub fn disassemble(&self) -> String {
+        let instructions = self.instructions();
+        let targets: HashSet<usize> = instructions
+            .iter()
+            .filter_map(|(_, instruction)| match instruction {
+                Some(Instruction {
+                    opcode: Opcode::Jnz,
+                    operand,
+                }) => usize::try_from(*operand).ok(),
+                _ => None,
+            })
+            .collect();
+
+        let mut listing = String::new();
+        for (address, instruction) in instructions {
+            let marker = if targets.contains(&address) { '>' } else { ' ' };
+            let code = format!("{},{}", self.program[address], self.program[address + 1]);
+            let (mnemonic, pseudo_code) = match instruction {
+                Some(instruction) => (instruction.to_string(), instruction.pseudo_code()),
+                None => ("???".to_string(), "invalid opcode".to_string()),
+            };
+            listing.push_str(&format!(
+                "{}{:>3}: {} | {} | {}\n",
+                marker, address, code, mnemonic, pseudo_code
+            ));
+        }
+        for target in targets
+            .iter()
+            .filter(|&&t| t % 2 == 1 || t >= self.program.len())
+        {
+            listing.push_str(&format!("> {}: jump target outside the listing\n", target));
+        }
+        listing
+    }
+
+    /// Checks that the program is a single loop that outputs one value and shifts A right by
+    /// three bits per iteration, ending with `jnz 0`. Then every output only depends on the
+    /// bits of A that have not been shifted out yet.
+    fn check_quine_shape(&self) -> Result<()> {
+        let instructions = self
+            .instructions()
+            .into_iter()
+            .map(|(address, instruction)| {
+                instruction.with_context(|| format!("Invalid opcode at address {}", address))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let count = |opcode: Opcode| instructions.iter().filter(|i| i.opcode == opcode).count();
+
+        ensure!(
+            instructions.last()
+                == Some(&Instruction {
+                    opcode: Opcode::Jnz,
+                    operand: 0,
+                })
+                && count(Opcode::Jnz) == 1,
+            "Program must be a single loop ending with `jnz 0`"
+        );
+        ensure!(
+            instructions.contains(&Instruction {
+                opcode: Opcode::Adv,
+                operand: 3,
+            }) && count(Opcode::Adv) == 1,
+            "Program must shift A by three bits with a single `adv 3`"
+        );
+        ensure!(
+            count(Opcode::Out) == 1,
+            "Program must output exactly one value per loop"
+        );
+        Ok(())
+    }
+
+    /// Output of the program when started with `a` in register A.
+    fn output_for(&self, a: u128) -> Result<Vec<u128>> {
+        let mut computer = self.clone();
+        computer.a = a;
+        computer.pc = 0;
+        computer.steps = 0;
+        computer.output.clear();
+        computer.run()?;
+        Ok(computer.output)
+    }
+
+    /// The smallest value of register A that makes the program output itself.
+    ///
+    /// Each loop iteration outputs one value and drops the lowest three bits of A, so the last
+    /// output only depends on the highest three bits of A. Working backwards from the last
+    /// output, every candidate is extended by three more bits and kept if the program then
+    /// outputs the matching end of itself.
+    pub fn find_quine_a(&self) -> Result<u128> {
+        self.check_quine_shape()?;
+
+        let mut candidates = vec![0];
+        for start in (0..self.program.len()).rev() {
+            let expected = &self.program[start..];
+            let mut extended = Vec::new();
+            for a in candidates
+                .iter()
+                .flat_map(|a| (0..8).map(move |bits| a << 3 | bits))
+            {
+                if self.output_for(a)? == expected {
+                    extended.push(a);
+                }
+            }
+            candidates = extended;
+        }
+        candidates
+            .into_iter()
+            .min()
+            .context("No value of A makes the program output itself")
+    }
+
+    /// Runs the program symbolically from address 0 up to the first `jnz`, giving every output
+    /// and the registers as expressions of the registers at the start.
+    ///
+    /// For a program shaped like the puzzle this is one iteration of its loop, so it shows
+    /// which bits of A each output digit is computed from.
+    pub fn symbolic_loop(&self) -> Result<LoopBody> {
+        let mut registers = ['A', 'B', 'C'].map(Expr::Register);
+        let mut outputs = Vec::new();
+        for (pc, instruction) in self.instructions() {
+            let instruction =
+                instruction.with_context(|| format!("Invalid opcode at address {}", pc))?;
+            let operand = instruction.operand;
+            let combo = |registers: &[Expr; 3]| match operand {
+                0..=3 => Ok(Expr::Const(operand)),
+                4..=6 => Ok(registers[operand as usize - 4].clone()),
+                _ => Err(anyhow!(
+                    "Invalid combo operand {} at address {}",
+                    operand,
+                    pc
+                )),
+            };
+            let [a, b, c] = &registers;
+            match instruction.opcode {
+                Opcode::Adv => registers[0] = Expr::divide(a.clone(), combo(&registers)?),
+                Opcode::Bxl => registers[1] = Expr::xor(b.clone(), Expr::Const(operand)),
+                Opcode::Bst => registers[1] = Expr::mod8(combo(&registers)?),
+                Opcode::Jnz => {
+                    return Ok(LoopBody {
+                        outputs,
+                        registers,
+                        jump: Some(pc),
+                    })
+                }
+                Opcode::Bxc => registers[1] = Expr::xor(b.clone(), c.clone()),
+                Opcode::Out => outputs.push(Expr::mod8(combo(&registers)?)),
+                Opcode::Bdv => registers[1] = Expr::divide(a.clone(), combo(&registers)?),
+                Opcode::Cdv => registers[2] = Expr::divide(a.clone(), combo(&registers)?),
+            }
+        }
+        Ok(LoopBody {
+            outputs,
+            registers,
+            jump: None,
+        })
+    }
+
+    /// The value of a combo operand.
+    fn combo(&self, operand: u128) -> std::result::Result<u128, Halt> {
+        match operand {
+            0..=3 => std::result::Result::Ok(operand),
+            4 => std::result::Result::Ok(self.a),
+            5 => std::result::Result::Ok(self.b),
+            6 => std::result::Result::Ok(self.c),
+            _ => Err(Halt::InvalidOperand {
+                pc: self.pc,
+                operand,
+            }),
+        }
+    }
+
+    /// A divided by two to the power of the combo operand, as done by `adv`, `bdv` and `cdv`.
+    fn divide(&self, operand: u128) -> std::result::Result<u128, Halt> {
+        let shift = self.combo(operand)?;
+        let overflow = Halt::ShiftOverflow { pc: self.pc, shift };
+        let shift = u32::try_from(shift).map_err(|_| overflow)?;
+        let divisor = 1u128.checked_shl(shift).ok_or(overflow)?;
+        std::result::Result::Ok(self.a / divisor)
+    }
+}
+
+/// One executed instruction with the registers after it ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceStep {
+    /// Address the instruction was at.
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub a: u128,
+    pub b: u128,
+    pub c: u128,
+    /// The value printed by an `out` instruction.
+    pub output: Option<u128>,
+}
+
+/// Why [`Debugger::run`] or [`Debugger::run_until_outputs`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The computer cannot continue, [`Halt::Finished`] when the program ran to its end.
+    Halted(Halt),
+    /// The next instruction is at this breakpoint and has not run yet.
+    Breakpoint(usize),
+    /// At least the requested number of values has been output, this many in total.
+    Outputs(usize),
+    /// The last instruction triggered a watch on `register`, which now holds `value`.
+    Watch { register: char, value: u128 },
+}
+
+/// Checks the value of a register before and after an instruction.
+type WatchCondition = Box<dyn Fn(u128, u128) -> bool>;
+
+/// Runs a [`Computer`] step by step, stopping at breakpoints and register watches and
+/// recording every step.
+pub struct Debugger {
+    pub computer: Computer,
+    breakpoints: HashSet<usize>,
+    watches: Vec<(char, WatchCondition)>,
+    /// The breakpoint the last run stopped at, which the next run leaves without stopping.
+    stopped_at: Option<usize>,
+    trace: Vec<TraceStep>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            computer,
+            breakpoints: HashSet::new(),
+            watches: Vec::new(),
+            stopped_at: None,
+            trace: Vec::new(),
+        }
+    }
+
+    /// Stops before the instruction at `pc` is executed.
+    pub fn add_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn remove_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.remove(&pc);
+    }
+
+    /// Stops after every instruction that changes `register`, `'A'`, `'B'` or `'C'`.
+    pub fn watch_changes(&mut self, register: char) -> Result<()> {
+        self.add_watch(register, Box::new(|before, after| before != after))
+    }
+
+    /// Stops after every instruction that makes the value of `register` meet `predicate`, when
+    /// it did not before.
+    pub fn watch_value(
+        &mut self,
+        register: char,
+        predicate: impl Fn(u128) -> bool + 'static,
+    ) -> Result<()> {
+        let condition = move |before, after| !predicate(before) && predicate(after);
+        self.add_watch(register, Box::new(condition))
+    }
+
+    pub fn clear_watches(&mut self) {
+        self.watches.clear();
+    }
+
+    fn add_watch(&mut self, register: char, condition: WatchCondition) -> Result<()> {
+        ensure!(
+            matches!(register, 'A' | 'B' | 'C'),
+            "Invalid register {:?}, expected A, B or C",
+            register
+        );
+        self.watches.push((register, condition));
+        Ok(())
+    }
+
+    /// The first watch triggered by going from the registers `before` to the current ones.
+    fn triggered_watch(&self, before: [u128; 3]) -> Option<Stop> {
+        let after = self.computer.registers();
+        self.watches.iter().find_map(|(register, condition)| {
+            let idx = (*register as u8 - b'A') as usize;
+            condition(before[idx], after[idx]).then_some(Stop::Watch {
+                register: *register,
+                value: after[idx],
+            })
+        })
+    }
+
+    /// Executes a single instruction, see [`Computer::step`].
+    pub fn step(&mut self) -> std::result::Result<TraceStep, Halt> {
+        self.stopped_at = None;
+        let pc = self.computer.pc;
+        let printed = self.computer.output.len();
+        let instruction = self.computer.step()?;
+        let [a, b, c] = self.computer.registers();
+        let step = TraceStep {
+            pc,
+            instruction,
+            a,
+            b,
+            c,
+            output: self.computer.output.get(printed).copied(),
+        };
+        self.trace.push(step);
+        std::result::Result::Ok(step)
+    }
+
+    /// Runs until the next breakpoint or watch, or until the program halts. When stopped at a
+    /// breakpoint, the instruction there is executed first, so calling it again continues.
+    pub fn run(&mut self) -> Stop {
+        self.run_while(|_| true)
+    }
+
+    /// Runs until `count` values have been output in total, a breakpoint or watch is hit or the
+    /// program halts.
+    pub fn run_until_outputs(&mut self, count: usize) -> Stop {
+        if self.computer.output.len() >= count {
+            return Stop::Outputs(self.computer.output.len());
+        }
+        self.run_while(|computer| computer.output.len() < count)
+    }
+
+    fn run_while(&mut self, mut proceed: impl FnMut(&Computer) -> bool) -> Stop {
+        let mut resumed = self.stopped_at.take();
+        loop {
+            let pc = self.computer.pc;
+            if self.breakpoints.contains(&pc) && resumed != Some(pc) {
+                self.stopped_at = Some(pc);
+                return Stop::Breakpoint(pc);
+            }
+            resumed = None;
+            let before = self.computer.registers();
+            if let Err(halt) = self.step() {
+                return Stop::Halted(halt);
+            }
+            if let Some(stop) = self.triggered_watch(before) {
+                return stop;
+            }
+            if !proceed(&self.computer) {
+                return Stop::Outputs(self.computer.output.len());
+            }
+        }
+    }
+
+    /// All steps executed so far.
+    pub fn trace(&self) -> &[TraceStep] {
+        &self.trace
+    }
+
+    /// The trace as a table with one row per step, registers as they are after the step.
+    pub fn trace_table(&self) -> String {
+        let width = |value: fn(&TraceStep) -> u128| {
+            self.trace
+                .iter()
+                .map(|step| value(step).to_string().len())
+                .max()
+                .unwrap_or(0)
+                .max(1)
+        };
+        let (a, b, c) = (width(|s| s.a), width(|s| s.b), width(|s| s.c));
+
+        let mut table = format!("step  pc instr  {:>a$} {:>b$} {:>c$} out\n", "A", "B", "C");
+        for (idx, step) in self.trace.iter().enumerate() {
+            let output = step.output.map_or(String::new(), |value| value.to_string());
+            let instruction = step.instruction.to_string();
+            let row = format!(
+                "{:>4} {:>3} {:<6} {:>a$} {:>b$} {:>c$} {}",
+                idx + 1,
+                step.pc,
+                instruction,
+                step.a,
+                step.b,
+                step.c,
+                output
+            );
+            table.push_str(row.trim_end());
+            table.push('\n');
+        }
+        table
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+
+    type Input = Computer;
+    type Answer1 = String;
+    type Answer2 = u128;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        Computer::from_reader(reader)
+    }
+
+    fn part1(&self, computer: &Self::Input) -> Result<String> {
+        let mut computer = computer.clone();
+        // computer.print();
+        computer.run()?;
+        Ok(computer.output.iter().join(","))
+    }
+
+    fn part2(&self, computer: &Self::Input) -> Result<u128> {
+        computer.find_quine_a()
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!("4,6,3,5,6,3,5,2,1,0", self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(117440, self.part2_str(TEST_2)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE_LISTING: &str = "\
+>  0: 2,4 | bst A | B = A % 8
+   2: 1,5 | bxl 5 | B = B ^ 5
+   4: 7,5 | cdv B | C = A / 2**B
+   6: 1,6 | bxl 6 | B = B ^ 6
+   8: 4,1 | bxc - | B = B ^ C
+  10: 5,5 | out B | print(B % 8)
+  12: 0,3 | adv 3 | A = A / 2**3
+  14: 3,0 | jnz 0 | if A != 0: goto 0
+";
+
+    fn with_program(program: &str) -> Computer {
+        let input = format!(
+            "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            program
+        );
+        Day17.parse(&mut input.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn disassemble() {
+        let computer = with_program("2,4,1,5,7,5,1,6,4,1,5,5,0,3,3,0");
+        assert_eq!(PUZZLE_LISTING, computer.disassemble());
+
+        let listing = with_program("0,7,8,1,3,3").disassemble();
+        let expected = [
+            "   0: 0,7 | adv ?7 | A = A / 2**?7",
+            "   2: 8,1 | ??? | invalid opcode",
+            "   4: 3,3 | jnz 3 | if A != 0: goto 3",
+            "> 3: jump target outside the listing",
+        ];
+        assert_eq!(expected.to_vec(), listing.lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn assemble() {
+        let computer = Computer::assemble(
+            "Register A: 60589763\n\
+             \n\
+             start:\n\
+             bst A   ; B = A % 8\n\
+             bxl 5\n\
+             cdv B\n\
+             bxl 6\n\
+             bxc 1   ; the operand is ignored\n\
+             out B\n\
+             adv 3\n\
+             jnz start\n",
+        )
+        .unwrap();
+        assert_eq!((60589763, 0, 0), (computer.a, computer.b, computer.c));
+        assert_eq!(
+            with_program("2,4,1,5,7,5,1,6,4,1,5,5,0,3,3,0").program,
+            computer.program
+        );
+
+        let computer =
+            Computer::assemble("Register A: 2024\nloop: adv 1\nout A\njnz loop").unwrap();
+        assert_eq!(vec![0, 1, 5, 4, 3, 0], computer.program);
+        assert_eq!(
+            vec![4, 0, 4, 0],
+            Computer::assemble("bxc\nbxc -").unwrap().program
+        );
+    }
+
+    #[test]
+    fn quine_shape() {
+        let err = with_program("0,1,5,4,3,0").find_quine_a().unwrap_err();
+        assert_eq!(
+            "Program must shift A by three bits with a single `adv 3`",
+            err.to_string()
+        );
+        let err = with_program("0,3,5,4").find_quine_a().unwrap_err();
+        assert_eq!(
+            "Program must be a single loop ending with `jnz 0`",
+            err.to_string()
+        );
+        let err = with_program("0,3,5,4,5,4,3,0").find_quine_a().unwrap_err();
+        assert_eq!(
+            "Program must output exactly one value per loop",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn assembler_errors() {
+        let error = |source: &str| {
+            let err = Computer::assemble(source).err().unwrap();
+            match err.downcast_ref::<ParseError>() {
+                Some(err) => (err.line, err.column, err.message.clone()),
+                None => (0, 0, err.to_string()),
+            }
+        };
+        assert_eq!(
+            (2, 5, "Combo operand 7 is reserved".to_string()),
+            error("bst A\nout 7")
+        );
+        assert_eq!((1, 1, "Unknown instruction".to_string()), error("mov A"));
+        assert_eq!(
+            (1, 5, "Literal operand must be 0 to 7".to_string()),
+            error("bxl 8")
+        );
+        assert_eq!(
+            (2, 1, "Duplicate label".to_string()),
+            error("a: bst A\na: jnz a")
+        );
+        assert_eq!((1, 5, "Unknown label".to_string()), error("jnz end"));
+
+        let err = Computer::new([0; 3], vec![0, 1, 5]).err().unwrap();
+        assert_eq!(
+            "Program has odd length 3, the last opcode has no operand",
+            err.to_string()
+        );
+        assert!(Computer::new([0; 3], vec![5, 7]).is_err());
+        assert!(Computer::new([0; 3], vec![1, 7, 8, 0]).is_err());
+        assert!(Computer::new([0; 3], vec![1, 7]).is_ok());
+    }
+
+    #[test]
+    fn debugger() {
+        let computer = Day17.parse(&mut TEST.as_bytes()).unwrap();
+        let mut debugger = Debugger::new(computer);
+        debugger.add_breakpoint(4);
+
+        let step = debugger.step().unwrap();
+        assert_eq!((0, 364, None), (step.pc, step.a, step.output));
+        assert_eq!(Stop::Breakpoint(4), debugger.run());
+        assert_eq!(Stop::Breakpoint(4), debugger.run());
+        assert_eq!(vec![4, 6], debugger.computer.output());
+
+        debugger.remove_breakpoint(4);
+        assert_eq!(Stop::Outputs(5), debugger.run_until_outputs(5));
+        assert_eq!(Stop::Outputs(5), debugger.run_until_outputs(3));
+        assert_eq!(Stop::Halted(Halt::Finished), debugger.run());
+        assert_eq!(Err(Halt::Finished), debugger.step());
+        assert_eq!(
+            "4,6,3,5,6,3,5,2,1,0",
+            debugger.computer.output().iter().join(",")
+        );
+        assert_eq!(30, debugger.trace().len());
+
+        let table = debugger.trace_table();
+        let rows: Vec<_> = table.lines().take(4).collect();
+        let expected = [
+            "step  pc instr    A B C out",
+            "   1   0 adv 1  364 0 0",
+            "   2   2 out A  364 0 0 4",
+            "   3   4 jnz 0  364 0 0",
+        ];
+        assert_eq!(expected.to_vec(), rows);
+
+        // A breakpoint at the start is hit before anything runs, and left by the next run
+        let mut debugger = Debugger::new(Day17.parse(&mut TEST.as_bytes()).unwrap());
+        debugger.add_breakpoint(0);
+        assert_eq!(Stop::Breakpoint(0), debugger.run());
+        assert!(debugger.trace().is_empty());
+        assert_eq!(Stop::Breakpoint(0), debugger.run());
+        assert_eq!(3, debugger.trace().len());
+    }
+
+    #[test]
+    fn watches() {
+        let computer = Day17.parse(&mut TEST.as_bytes()).unwrap();
+        let mut debugger = Debugger::new(computer);
+        debugger.watch_changes('A').unwrap();
+        let halved = Stop::Watch {
+            register: 'A',
+            value: 364,
+        };
+        assert_eq!(halved, debugger.run());
+        assert_eq!(1, debugger.trace().len());
+        let halved_again = Stop::Watch {
+            register: 'A',
+            value: 182,
+        };
+        assert_eq!(halved_again, debugger.run());
+        assert_eq!(4, debugger.trace().len());
+        assert_eq!(vec![4], debugger.computer.output());
+
+        debugger.clear_watches();
+        debugger.watch_value('A', |a| a < 10).unwrap();
+        let small = Stop::Watch {
+            register: 'A',
+            value: 5,
+        };
+        assert_eq!(small, debugger.run());
+        assert_eq!(Stop::Halted(Halt::Finished), debugger.run());
+        assert!(debugger.watch_changes('D').is_err());
+    }
+
+    #[test]
+    fn halts() {
+        let halt = |mut computer: Computer| computer.run().unwrap_err();
+
+        let endless = Computer::new([1, 0, 0], vec![3, 0]).unwrap();
+        assert_eq!(Halt::RepeatedState { pc: 0 }, halt(endless));
+        let mut slow = Computer::new([1 << 100, 0, 0], vec![0, 1, 3, 0]).unwrap();
+        slow.set_step_limit(10);
+        assert_eq!(Halt::StepLimit(10), halt(slow));
+        let wide = Computer::new([1, 200, 0], vec![0, 5]).unwrap();
+        assert_eq!(Halt::ShiftOverflow { pc: 0, shift: 200 }, halt(wide));
+
+        assert_eq!(
+            Halt::InvalidOperand { pc: 2, operand: 7 },
+            halt(with_program("1,2,5,7"))
+        );
+        assert_eq!(
+            Halt::InvalidOpcode { pc: 0, code: 8 },
+            halt(with_program("8,0"))
+        );
+        let mut truncated = with_program("1,2,0");
+        assert_eq!(Err(Halt::TruncatedInstruction { pc: 2 }), truncated.run());
+        assert_eq!((2, 1), (truncated.registers()[1], truncated.steps()));
+
+        let mut finished = with_program("1,2");
+        assert!(finished.run().is_ok());
+        assert_eq!(Err(Halt::Finished), finished.step());
+    }
+
+    #[test]
+    fn symbolic_loop() {
+        let computer = with_program("2,4,1,5,7,5,1,6,4,1,5,5,0,3,3,0");
+        let body = computer.symbolic_loop().unwrap();
+        let expected = "\
+out 0: (((A % 8) ^ 3) ^ (A / 2**((A % 8) ^ 5))) % 8  [A bits 0..10]
+A = A / 2**3
+B = (((A % 8) ^ 3) ^ (A / 2**((A % 8) ^ 5)))
+C = A / 2**((A % 8) ^ 5)
+jnz at 14
+";
+        assert_eq!(expected, body.to_string());
+
+        let body = Day17.parse(&mut TEST.as_bytes()).unwrap().symbolic_loop();
+        assert_eq!(Some(1..4), body.unwrap().outputs[0].a_bits(3));
+        let body = with_program("1,3,5,5,0,3,0,3").symbolic_loop().unwrap();
+        assert_eq!("(B ^ 3) % 8", body.outputs[0].to_string());
+        assert_eq!(None, body.outputs[0].a_bits(3));
+        assert_eq!(
+            Expr::divide(Expr::Register('A'), Expr::Const(6)),
+            body.registers[0]
+        );
+        assert_eq!(None, body.jump);
+
+        let a = Expr::Register('A');
+        assert_eq!(
+            a,
+            Expr::xor(Expr::xor(a.clone(), Expr::Const(5)), Expr::Const(5))
+        );
+        assert_eq!(
+            Expr::Const(2),
+            Expr::mod8(Expr::divide(Expr::Const(40), Expr::Const(2)))
+        );
+        assert!(with_program("5,7").symbolic_loop().is_err());
+    }
+}
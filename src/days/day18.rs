@@ -0,0 +1,286 @@
+use crate::*;
+use anyhow::*;
+use std::cmp::PartialEq;
+use std::io::BufRead;
+
+const TEST: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+const TEST_SIZE: (usize, usize) = (7, 7);
+const REAL_SIZE: (usize, usize) = (71, 71);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Field {
+    Free,
+    Wall(usize),
+    Exit,
+}
+
+/// The falling bytes, which must all land inside a memory space of `size`.
+fn read_input(reader: &mut dyn BufRead, size: (usize, usize)) -> Result<Vec<(usize, usize)>> {
+    let input = std::io::read_to_string(reader)?;
+    let mut byte_drops = Vec::new();
+    for line in parse::lines(&input) {
+        let [x, y] = line.record("{},{}")?;
+        if x >= size.0 || y >= size.1 {
+            bail!(line.error(format!(
+                "Byte outside the {}x{} memory space",
+                size.0, size.1
+            )));
+        }
+        byte_drops.push((x, y));
+    }
+    Ok(byte_drops)
+}
+
+/// The memory space with the exit in the bottom right corner and every byte as a wall,
+/// marked with the time it falls at.
+fn build_map(size: (usize, usize), byte_drops: &[(usize, usize)]) -> Vec<Vec<Field>> {
+    let mut map = vec![vec![Field::Free; size.0]; size.1];
+    for (time, (x, y)) in byte_drops.iter().enumerate() {
+        // A byte falling on an already corrupted position changes nothing
+        if map[*y][*x] == Field::Free {
+            map[*y][*x] = Field::Wall(time);
+        }
+    }
+    map[size.1 - 1][size.0 - 1] = Field::Exit;
+    map
+}
+
+/// Steps from the top left corner to the exit once `fallen` bytes have fallen, `None` if the
+/// exit cannot be reached.
+fn shortest_path(map: &[Vec<Field>], fallen: usize) -> Option<usize> {
+    let size = (map[0].len(), map.len());
+    let exit = Point::from_usize(size.0 - 1, size.1 - 1);
+    let is_free = |pos: Point| match pos.to_usize() {
+        Some((x, y)) => !matches!(map[y][x], Field::Wall(time) if time < fallen),
+        None => false,
+    };
+    let result = search::bfs(
+        [Point::ORIGIN],
+        |&pos| {
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |direction| pos.checked_step(direction, size))
+                .filter(|&next| is_free(next))
+        },
+        |&pos| pos == exit,
+    );
+    result.goal_cost()
+}
+
+/// Earliest time the exit is reached when the bytes keep falling while walking, `None` if it
+/// cannot be reached at all.
+///
+/// The byte with index `t` is a wall from step `t` on, so a step ending at time `t` cannot
+/// enter its position. With `wait`, standing still for a step is allowed as well. This never
+/// changes the result: walls only accumulate, so leaving a wait out of a path moves every later
+/// step one step earlier, onto a position that was free at that time as well.
+fn earliest_arrival(map: &[Vec<Field>], wait: bool) -> Option<usize> {
+    let size = (map[0].len(), map.len());
+    let exit = Point::from_usize(size.0 - 1, size.1 - 1);
+    // Once every byte has fallen the map stays the same, so later times are all alike
+    let settled = map
+        .iter()
+        .flatten()
+        .filter_map(|field| match field {
+            Field::Wall(time) => Some(*time),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let is_free = |pos: Point, time: usize| match pos.to_usize() {
+        Some((x, y)) => !matches!(map[y][x], Field::Wall(fall) if fall <= time),
+        None => false,
+    };
+    let result = search::bfs(
+        [(Point::ORIGIN, 0)],
+        |&(pos, time)| {
+            let next_time = time + 1;
+            let stay = wait.then_some(pos);
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |direction| pos.checked_step(direction, size))
+                .chain(stay)
+                .filter(move |&next| is_free(next, next_time))
+                .map(move |next| (next, next_time.min(settled)))
+        },
+        |&(pos, _)| pos == exit,
+    );
+    result.goal_cost()
+}
+
+#[allow(dead_code)]
+fn print_map(map: &[Vec<Field>]) {
+    let mut output = String::new();
+    for row in map.iter() {
+        for field in row.iter() {
+            output.push(match field {
+                Field::Free => '.',
+                Field::Wall(_) => '#',
+                Field::Exit => 'E',
+            });
+        }
+        output.push('\n');
+    }
+    println!("{}", output);
+}
+
+/// How part 1 treats the falling bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The first `num_byte_drop` bytes have fallen before walking, as in the puzzle.
+    Fallen,
+    /// The bytes fall one per step while walking, see [`earliest_arrival`].
+    Falling { wait: bool },
+}
+
+pub struct Day18 {
+    /// Width and height of the memory space.
+    pub map_size: (usize, usize),
+    /// Number of bytes that have fallen when part 1 looks for a path.
+    pub num_byte_drop: usize,
+    pub mode: Mode,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Self {
+            map_size: REAL_SIZE,
+            num_byte_drop: 1024,
+            mode: Mode::Fallen,
+        }
+    }
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "RAM Run";
+
+    /// Positions of all falling bytes in the order they fall.
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = Point;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        read_input(reader, self.map_size)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        let map = build_map(self.map_size, input);
+        // print_map(&map);
+        let steps = match self.mode {
+            Mode::Fallen => shortest_path(&map, self.num_byte_drop),
+            Mode::Falling { wait } => earliest_arrival(&map, wait),
+        };
+        steps.context("No path to the exit")
+    }
+
+    /// Binary search for the number of fallen bytes that first cuts off the exit, the last of
+    /// them is the blocking one.
+    fn part2(&self, input: &Self::Input) -> Result<Point> {
+        let map = build_map(self.map_size, input);
+        ensure!(
+            shortest_path(&map, input.len()).is_none(),
+            "The exit can still be reached after all bytes have fallen"
+        );
+
+        // The exit is reachable after `open` bytes and cut off after `blocked`
+        let (mut open, mut blocked) = (0, input.len());
+        while blocked - open > 1 {
+            let mid = open + (blocked - open) / 2;
+            if shortest_path(&map, mid).is_some() {
+                open = mid;
+            } else {
+                blocked = mid;
+            }
+        }
+        let (x, y) = input[blocked - 1];
+        Ok(Point::from_usize(x, y))
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        let example = Day18 {
+            map_size: TEST_SIZE,
+            num_byte_drop: 12,
+            mode: Mode::Fallen,
+        };
+        match part {
+            Part::One => {
+                ensure_eq!(22, example.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(Point::new(6, 1), example.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn set_option(&mut self, name: &str, value: &str) -> Result<()> {
+        match (name, value) {
+            ("mode", "fallen") => self.mode = Mode::Fallen,
+            ("mode", "falling") => self.mode = Mode::Falling { wait: false },
+            ("mode", "falling-wait") => self.mode = Mode::Falling { wait: true },
+            ("mode", _) => bail!(
+                "Invalid mode {}, expected fallen, falling or falling-wait",
+                value
+            ),
+            _ => bail!("Day 18 has no option {}, expected mode", name),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falling_bytes() {
+        // The byte at (0, 1) is a wall from step 1, just when the first step would enter it
+        let map = build_map((3, 3), &[(1, 0), (0, 1)]);
+        assert_eq!(None, shortest_path(&map, 2));
+        assert_eq!(None, earliest_arrival(&map, false));
+        let map = build_map((3, 3), &[(1, 0), (2, 0), (0, 1)]);
+        assert_eq!(Some(4), earliest_arrival(&map, false));
+        assert_eq!(Some(4), earliest_arrival(&map, true));
+
+        let corridor = build_map((3, 1), &[(1, 0)]);
+        assert_eq!(None, earliest_arrival(&corridor, false));
+        assert_eq!(None, earliest_arrival(&corridor, true));
+
+        let mut day = Day18 {
+            map_size: TEST_SIZE,
+            num_byte_drop: 12,
+            mode: Mode::Fallen,
+        };
+        Solution::set_option(&mut day, "mode", "falling").unwrap();
+        assert_eq!(12, day.part1_str(TEST).unwrap());
+        assert!(Solution::set_option(&mut day, "mode", "flying").is_err());
+    }
+}
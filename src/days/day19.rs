@@ -0,0 +1,107 @@
+use crate::*;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+const TEST: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+fn read_designs(reader: &mut dyn BufRead) -> Result<(Vec<String>, Vec<String>)> {
+    let input = std::io::read_to_string(reader)?;
+    let [patterns, designs] = parse::n_sections(&input)?;
+    let [patterns] = &patterns.lines[..] else {
+        bail!(patterns.lines[1].error("Expected the patterns on a single line"));
+    };
+    let designs = designs
+        .lines
+        .iter()
+        .map(|line| line.text.to_string())
+        .collect();
+    Ok((patterns.list(',')?, designs))
+}
+
+fn design_matches_patterns(
+    patterns: &[String],
+    design: &str,
+    chache: &mut HashMap<String, usize>,
+) -> usize {
+    if let Some(count) = chache.get(design) {
+        return *count;
+    }
+
+    let mut count = 0;
+    for pat in patterns {
+        if pat.len() > design.len() {
+            continue;
+        }
+        if design[0..pat.len()] == *pat {
+            if design.len() == pat.len() {
+                count += 1;
+                continue;
+            } else {
+                count += design_matches_patterns(patterns, &design[pat.len()..], chache);
+            };
+        }
+    }
+    chache.insert(design.to_string(), count);
+    count
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Linen Layout";
+
+    /// The available towel patterns and the desired designs.
+    type Input = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        read_designs(reader)
+    }
+
+    fn part1(&self, (patterns, designs): &Self::Input) -> Result<usize> {
+        let mut cache = HashMap::new();
+
+        let valid_designs = designs
+            .iter()
+            .filter(|d| design_matches_patterns(patterns, d, &mut cache) > 0);
+
+        Ok(valid_designs.count())
+    }
+
+    fn part2(&self, (patterns, designs): &Self::Input) -> Result<usize> {
+        let mut cache = HashMap::new();
+
+        let valid_designs = designs
+            .iter()
+            .map(|d| design_matches_patterns(patterns, d, &mut cache))
+            .filter(|&c| c > 0);
+
+        Ok(valid_designs.sum())
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(6, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                ensure_eq!(16, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
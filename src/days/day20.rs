@@ -0,0 +1,372 @@
+use crate::*;
+use anyhow::*;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+const TEST: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+/// Picoseconds saved and number of cheats saving them in the example, from the puzzle.
+const EXAMPLE_SAVINGS_1: [(usize, usize); 11] = [
+    (2, 14),
+    (4, 14),
+    (6, 2),
+    (8, 4),
+    (10, 2),
+    (12, 3),
+    (20, 1),
+    (36, 1),
+    (38, 1),
+    (40, 1),
+    (64, 1),
+];
+
+/// Like [`EXAMPLE_SAVINGS_1`] for cheats of up to 20 picoseconds, listed from 50 on.
+const EXAMPLE_SAVINGS_2: [(usize, usize); 14] = [
+    (50, 32),
+    (52, 31),
+    (54, 29),
+    (56, 39),
+    (58, 25),
+    (60, 23),
+    (62, 20),
+    (64, 19),
+    (66, 12),
+    (68, 14),
+    (70, 12),
+    (72, 22),
+    (74, 4),
+    (76, 3),
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Field {
+    Free,
+    Wall,
+    Exit,
+    Start,
+}
+
+fn parse_maze<R: BufRead>(reader: R) -> Result<Vec<Vec<Field>>> {
+    let mut maze = Vec::new();
+    for (y, line) in reader.lines().enumerate() {
+        let line = line?;
+        let mut row = Vec::new();
+        for (x, c) in line.trim_end().chars().enumerate() {
+            row.push(match c {
+                '#' => Field::Wall,
+                'E' => Field::Exit,
+                'S' => Field::Start,
+                '.' => Field::Free,
+                _ => bail!(ParseError::new(y + 1, x + 1, c, "Invalid maze field")),
+            });
+        }
+        maze.push(row);
+    }
+    Ok(maze)
+}
+
+fn find_start_end(maze: &[Vec<Field>]) -> Result<(Point, Point)> {
+    let find = |wanted: Field| {
+        maze.iter().enumerate().find_map(|(y, row)| {
+            let x = row.iter().position(|&field| field == wanted)?;
+            Some(Point::from_usize(x, y))
+        })
+    };
+    let start = find(Field::Start).context("No start 'S' in the maze")?;
+    let end = find(Field::Exit).context("No exit 'E' in the maze")?;
+    Ok((start, end))
+}
+
+/// Picoseconds from `from` to every position of the track, `None` for walls.
+fn distances(maze: &[Vec<Field>], from: Point) -> Grid<Option<usize>> {
+    let size = (maze.iter().map(Vec::len).max().unwrap_or(0), maze.len());
+    let is_track = |pos: Point| {
+        let field = pos.to_usize().and_then(|(x, y)| maze[y].get(x));
+        field.is_some_and(|&field| field != Field::Wall)
+    };
+    let result = search::bfs(
+        [from],
+        |&pos| {
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |direction| pos.checked_step(direction, size))
+                .filter(move |&next| is_track(next))
+        },
+        |_| false,
+    );
+    let mut grid = Grid::filled(size.0, size.1, None);
+    for (&pos, &distance) in result.distances() {
+        grid[pos] = Some(distance);
+    }
+    grid
+}
+
+/// A shortcut through the walls, from the last track position before it to the first after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cheat {
+    pub start: Point,
+    pub end: Point,
+    /// Picoseconds the cheat lasts, the Manhattan distance from `start` to `end`.
+    pub len: usize,
+    /// Picoseconds the race is shorter with the cheat.
+    pub saving: usize,
+}
+
+/// The race track with the distance of every track position from the start and to the exit.
+///
+/// A cheat from `a` to `b` takes the Manhattan distance between them, so the race with it
+/// takes the time from the start to `a`, the cheat and the time from `b` to the exit; no
+/// cheat needs the maze searched again.
+pub struct Track {
+    from_start: Grid<Option<usize>>,
+    to_end: Grid<Option<usize>>,
+    /// Picoseconds the race takes without cheating.
+    pub time: usize,
+}
+
+impl Track {
+    pub fn new(maze: &[Vec<Field>]) -> Result<Self> {
+        let (start, end) = find_start_end(maze)?;
+        let from_start = distances(maze, start);
+        let time = from_start[end].context("The exit cannot be reached")?;
+        Ok(Self {
+            from_start,
+            to_end: distances(maze, end),
+            time,
+        })
+    }
+
+    /// Every cheat of at most `max_len` picoseconds that saves time.
+    ///
+    /// Each track position is visited once, trying all ends within `max_len` steps.
+    fn cheats(&self, max_len: usize) -> impl Iterator<Item = Cheat> + '_ {
+        let reach = max_len as isize;
+        self.from_start
+            .iter()
+            .filter_map(|(pos, distance)| Some((pos, (*distance)?)))
+            .flat_map(move |(start, before)| {
+                (-reach..=reach).flat_map(move |dy| {
+                    let rest = reach - dy.abs();
+                    (-rest..=rest).filter_map(move |dx| {
+                        let end = start + Point::new(dx, dy);
+                        let after = (*self.to_end.get(end)?)?;
+                        let len = start.manhattan_distance(end);
+                        let time = before + len + after;
+                        let saving = self.time.checked_sub(time).filter(|&saving| saving > 0)?;
+                        Some(Cheat {
+                            start,
+                            end,
+                            len,
+                            saving,
+                        })
+                    })
+                })
+            })
+    }
+
+    /// How many cheats of at most `max_len` picoseconds save how many picoseconds.
+    pub fn savings(&self, max_len: usize) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for cheat in self.cheats(max_len) {
+            *histogram.entry(cheat.saving).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Number of cheats of at most `max_len` picoseconds that save at least `threshold`.
+    pub fn count_cheats(&self, max_len: usize, threshold: usize) -> usize {
+        self.cheats(max_len)
+            .filter(|cheat| cheat.saving >= threshold)
+            .count()
+    }
+
+    /// The cheats of at most `max_len` picoseconds that save at least `min_saving`, the best
+    /// first and equal ones by position.
+    pub fn list_cheats(&self, max_len: usize, min_saving: usize) -> Vec<Cheat> {
+        let mut cheats: Vec<_> = self
+            .cheats(max_len)
+            .filter(|cheat| cheat.saving >= min_saving)
+            .collect();
+        cheats.sort_by_key(|cheat| {
+            (
+                Reverse(cheat.saving),
+                (cheat.start.y, cheat.start.x),
+                (cheat.end.y, cheat.end.x),
+            )
+        });
+        cheats
+    }
+}
+
+/// The cheats as CSV with a header line, one cheat per line.
+pub fn cheats_csv(cheats: &[Cheat]) -> String {
+    let mut csv = String::from("start_x,start_y,end_x,end_y,length,saving\n");
+    for cheat in cheats {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            cheat.start.x, cheat.start.y, cheat.end.x, cheat.end.y, cheat.len, cheat.saving
+        ));
+    }
+    csv
+}
+
+fn field_char(field: Field) -> char {
+    match field {
+        Field::Free => '.',
+        Field::Wall => '#',
+        Field::Exit => 'E',
+        Field::Start => 'S',
+    }
+}
+
+/// The maze with `cheat` drawn like in the puzzle: `1` for its first step and `2` where it
+/// ends. Longer cheats go horizontally first, then vertically, with `*` for the steps between.
+pub fn render_cheat(maze: &[Vec<Field>], cheat: &Cheat) -> String {
+    let mut chars: Vec<Vec<char>> = maze
+        .iter()
+        .map(|row| row.iter().map(|&field| field_char(field)).collect())
+        .collect();
+
+    let mut pos = cheat.start;
+    for step in 1..=cheat.len {
+        pos += if pos.x != cheat.end.x {
+            Point::new((cheat.end.x - pos.x).signum(), 0)
+        } else {
+            Point::new(0, (cheat.end.y - pos.y).signum())
+        };
+        let mark = match step {
+            1 => '1',
+            _ if step == cheat.len => '2',
+            _ => '*',
+        };
+        if let Some(c) = pos
+            .to_usize()
+            .and_then(|(x, y)| chars.get_mut(y)?.get_mut(x))
+        {
+            *c = mark;
+        }
+    }
+
+    let mut out = String::new();
+    for row in chars {
+        out.extend(row);
+        out.push('\n');
+    }
+    out
+}
+
+#[allow(dead_code)]
+fn print_maze(maze: &[Vec<Field>]) {
+    let mut out = String::new();
+    for row in maze {
+        out.extend(row.iter().map(|&field| field_char(field)));
+        out.push('\n');
+    }
+    println!("{}", out);
+}
+
+pub struct Day20 {
+    /// Minimum number of picoseconds a cheat must save to be counted.
+    pub threshold: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self { threshold: 100 }
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Race Condition";
+
+    type Input = Vec<Vec<Field>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        parse_maze(reader)
+    }
+
+    fn part1(&self, maze: &Self::Input) -> Result<usize> {
+        Ok(Track::new(maze)?.count_cheats(2, self.threshold))
+    }
+
+    fn part2(&self, maze: &Self::Input) -> Result<usize> {
+        Ok(Track::new(maze)?.count_cheats(20, self.threshold))
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        let track = Track::new(&self.parse(&mut TEST.as_bytes())?)?;
+        match part {
+            Part::One => {
+                ensure_eq!(BTreeMap::from(EXAMPLE_SAVINGS_1), track.savings(2));
+                ensure_eq!(0, self.part1_str(TEST)?);
+            }
+            Part::Two => {
+                let savings = track.savings(20).split_off(&50);
+                ensure_eq!(BTreeMap::from(EXAMPLE_SAVINGS_2), savings);
+                let example = Day20 { threshold: 50 };
+                ensure_eq!(285, example.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_cheats() {
+        let maze = Day20::default().parse(&mut TEST.as_bytes()).unwrap();
+        let track = Track::new(&maze).unwrap();
+        let cheats = track.list_cheats(2, 38);
+        assert_eq!(3, cheats.len());
+        let best = cheats[0];
+        assert_eq!(
+            (Point::new(7, 7), Point::new(5, 7), 2, 64),
+            (best.start, best.end, best.len, best.saving)
+        );
+        assert_eq!(
+            "start_x,start_y,end_x,end_y,length,saving\n7,7,5,7,2,64\n",
+            cheats_csv(&cheats[..1])
+        );
+
+        let rendered = render_cheat(&maze, &best);
+        assert_eq!(Some("###..21...#...#"), rendered.lines().nth(7));
+        assert_eq!(
+            TEST.lines()
+                .filter(|&line| line != "###..E#...#...#")
+                .count(),
+            rendered
+                .lines()
+                .zip(TEST.lines())
+                .filter(|(a, b)| a == b)
+                .count()
+        );
+
+        let long = track.list_cheats(20, 76);
+        assert_eq!(3, long.len());
+        let rendered = render_cheat(&maze, &long[0]);
+        assert_eq!(long[0].len, rendered.matches(['1', '2', '*']).count());
+    }
+}
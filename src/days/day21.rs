@@ -0,0 +1,451 @@
+use crate::*;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+const TEST: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+/// The shortest presses on the human's keypad for every example code, from the puzzle.
+const EXAMPLE_PRESSES: [(&str, &str); 5] = [
+    (
+        "029A",
+        "<vA<AA>>^AvAA<^A>A<v<A>>^AvA^A<vA>^A<v<A>^A>AAvA^A<v<A>A>^AAAvA<^A>A",
+    ),
+    (
+        "980A",
+        "<v<A>>^AAAvA^A<vA<AA>>^AvAA<^A>A<v<A>A>^AAAvA<^A>A<vA>^A<A>A",
+    ),
+    (
+        "179A",
+        "<v<A>>^A<vA<A>>^AAvAA<^A>A<v<A>>^AAvA^A<vA>^AA<A>A<v<A>A>^AAAvA<^A>A",
+    ),
+    (
+        "456A",
+        "<v<A>>^AA<vA<A>>^AAvAA<^A>A<vA>^A<A>A<vA>^A<A>A<v<A>A>^AAvA<^A>A",
+    ),
+    (
+        "379A",
+        "<v<A>>^AvA^A<vA<AA>>^AAvA<^A>AAvA^A<vA>^AA<A>A<v<A>A>^AAAvA<^A>A",
+    ),
+];
+
+/// Layout of the keypad on the door, a blank is the gap.
+const NUMERIC_LAYOUT: &str = "789\n456\n123\n 0A";
+/// Layout of the keypads the robots are operated with.
+const DIRECTIONAL_LAYOUT: &str = " ^A\n<v>";
+
+/// Directional keypads operated by robots between the human and the numeric keypad.
+const ROBOTS_1: usize = 2;
+const ROBOTS_2: usize = 25;
+
+/// A keypad read from its layout, one character per key and a blank for the gap a robot arm
+/// must never point at.
+pub struct Keypad {
+    layout: Grid<Option<char>>,
+    keys: HashMap<char, Point>,
+}
+
+impl Keypad {
+    /// Reads a layout with one row per line; fails if a key appears twice.
+    pub fn new(layout: &str) -> Result<Self> {
+        let width = layout.lines().map(|line| line.chars().count()).max();
+        // Trailing blanks are easily lost, so short rows are padded with gap
+        let padded: Vec<_> = layout
+            .lines()
+            .map(|line| format!("{:width$}", line, width = width.unwrap_or(0)))
+            .collect();
+        let layout = Grid::parse(&padded.join("\n"), |c, _| Ok((c != ' ').then_some(c)))?;
+        let mut keys = HashMap::new();
+        for (pos, key) in layout.iter() {
+            if let Some(key) = *key {
+                ensure!(
+                    keys.insert(key, pos).is_none(),
+                    "Key {:?} appears twice",
+                    key
+                );
+            }
+        }
+        Ok(Self { layout, keys })
+    }
+
+    /// The keypad on the door.
+    pub fn numeric() -> Self {
+        Self::new(NUMERIC_LAYOUT).expect("valid numeric layout")
+    }
+
+    /// The keypad the robots are operated with.
+    pub fn directional() -> Self {
+        Self::new(DIRECTIONAL_LAYOUT).expect("valid directional layout")
+    }
+
+    /// Where `key` is on the keypad.
+    pub fn position(&self, key: char) -> Result<Point> {
+        self.keys
+            .get(&key)
+            .copied()
+            .with_context(|| format!("No key {:?} on the keypad", key))
+    }
+
+    /// Whether an arm at `pos` points at the gap or off the keypad.
+    pub fn is_gap(&self, pos: Point) -> bool {
+        !matches!(self.layout.get(pos), Some(Some(_)))
+    }
+
+    /// The key an arm at `pos` points at, `None` for the gap.
+    pub fn key_at(&self, pos: Point) -> Option<char> {
+        self.layout.get(pos).copied().flatten()
+    }
+
+    /// The keys pressed by an arm starting on `A` and following `moves`, which are given as
+    /// the characters of the keypad operating it. Fails with the arm's position as soon as it
+    /// points at the gap or off the keypad.
+    pub fn replay(&self, moves: &str) -> Result<String> {
+        let mut pos = self.position('A')?;
+        let mut pressed = String::new();
+        for (i, c) in moves.chars().enumerate() {
+            let m = Move::from_char(c)?;
+            if let Move::Step(direction) = m {
+                pos = pos.step(direction);
+            }
+            match self.layout.get(pos) {
+                Some(Some(key)) if m == Move::Activate => pressed.push(*key),
+                Some(Some(_)) => {}
+                Some(None) => bail!("Arm points at the gap at {} after move {}", pos, i + 1),
+                None => bail!("Arm points off the keypad at {} after move {}", pos, i + 1),
+            }
+        }
+        Ok(pressed)
+    }
+
+    /// Every shortest way to move an arm from `from` to `to` without pointing it at the gap,
+    /// each followed by pressing the key there. Ways with fewer changes of direction come
+    /// first, as every change costs extra presses on the keypad above, and of those the ones
+    /// going horizontally first.
+    pub fn moves(&self, from: char, to: char) -> Result<Vec<Vec<Move>>> {
+        let (start, end) = (self.position(from)?, self.position(to)?);
+        let size = self.layout.size();
+        // The direction of the last step is kept, so the paths spell out the moves
+        let result = search::bfs(
+            [(start, None)],
+            |&(pos, _)| {
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(move |direction| {
+                        Some((pos.checked_step(direction, size)?, direction))
+                    })
+                    .filter(|&(next, _)| !self.is_gap(next))
+                    .map(|(next, direction)| (next, Some(direction)))
+            },
+            |&(pos, _)| pos == end,
+        );
+
+        let mut ways: Vec<Vec<Move>> = result
+            .goals()
+            .iter()
+            .flat_map(|goal| result.all_paths_to(goal))
+            .map(|path| {
+                let steps = path.iter().filter_map(|&(_, step)| step.map(Move::Step));
+                steps.chain([Move::Activate]).collect()
+            })
+            .collect();
+        ways.sort_by_cached_key(|moves| {
+            let turns = moves.windows(2).filter(|pair| pair[0] != pair[1]).count();
+            let vertical_first = matches!(moves[0], Move::Step(d) if d.is_vertical());
+            (turns, vertical_first, move_string(moves))
+        });
+        Ok(ways)
+    }
+
+    /// Moves pressing `keys` one after the other, starting on `A` and taking the first of
+    /// [`Keypad::moves`] for every key.
+    pub fn type_keys(&self, keys: impl IntoIterator<Item = char>) -> Result<Vec<Move>> {
+        let mut from = 'A';
+        let mut moves = Vec::new();
+        for to in keys {
+            let way = self.moves(from, to)?.into_iter().next();
+            moves.extend(way.with_context(|| format!("No way from {:?} to {:?}", from, to))?);
+            from = to;
+        }
+        Ok(moves)
+    }
+}
+
+/// A button of the directional keypad: moving the arm one step, or pressing the key below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Move {
+    Step(Direction),
+    Activate,
+}
+
+impl Move {
+    /// The move of a key on the directional keypad.
+    pub fn from_char(c: char) -> Result<Self> {
+        Ok(match c {
+            '^' | 'v' | '<' | '>' => Move::Step(Direction::from_char(c)?),
+            'A' => Move::Activate,
+            _ => bail!("Invalid move {:?}", c),
+        })
+    }
+
+    /// The key on the directional keypad making the move.
+    pub fn to_char(self) -> char {
+        match self {
+            Move::Step(direction) => direction.as_char(),
+            Move::Activate => 'A',
+        }
+    }
+}
+
+/// The fewest presses the human needs to get keys pressed further down the robot chain,
+/// memoized by `(from, to, depth)`.
+///
+/// Depth 0 is the directional keypad the human uses, depth `n` the one operated by the `n`th
+/// robot. Every arm above the one moving rests on `A` between two presses, so the cost of a
+/// key only depends on the key before it and the depth.
+pub struct PressCost {
+    numeric: Keypad,
+    directional: Keypad,
+    cache: HashMap<(Move, Move, usize), usize>,
+}
+
+impl PressCost {
+    /// Costs for the puzzle's keypads, none worked out yet.
+    pub fn new() -> Self {
+        Self {
+            numeric: Keypad::numeric(),
+            directional: Keypad::directional(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Presses to move the arm over the directional keypad at `depth` from `from` to `to` and
+    /// press it.
+    fn key(&mut self, from: Move, to: Move, depth: usize) -> usize {
+        if depth == 0 {
+            return 1;
+        }
+        if let Some(&cost) = self.cache.get(&(from, to, depth)) {
+            return cost;
+        }
+        let candidates = self
+            .directional
+            .moves(from.to_char(), to.to_char())
+            .expect("every move is a key of the directional keypad");
+        let cost = candidates
+            .iter()
+            .map(|moves| self.sequence(moves, depth - 1))
+            .min()
+            .expect("every key of the directional keypad can be reached");
+        self.cache.insert((from, to, depth), cost);
+        cost
+    }
+
+    /// Presses to type `moves` on the directional keypad at `depth`, starting on `A`.
+    fn sequence(&mut self, moves: &[Move], depth: usize) -> usize {
+        let mut from = Move::Activate;
+        let mut presses = 0;
+        for &to in moves {
+            presses += self.key(from, to, depth);
+            from = to;
+        }
+        presses
+    }
+
+    /// Presses to type `code` on the numeric keypad through `robots` directional keypads.
+    pub fn code(&mut self, code: &str, robots: usize) -> Result<usize> {
+        let mut from = 'A';
+        let mut presses = 0;
+        for to in code.chars() {
+            let candidates = self.numeric.moves(from, to)?;
+            presses += candidates
+                .iter()
+                .map(|moves| self.sequence(moves, robots))
+                .min()
+                .with_context(|| format!("No way from {:?} to {:?}", from, to))?;
+            from = to;
+        }
+        Ok(presses)
+    }
+}
+
+impl Default for PressCost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The number in a code like `029A`, without leading zeros.
+fn numeric_part(code: &str) -> Result<usize> {
+    let digits: String = code.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits
+        .parse()
+        .with_context(|| format!("Code {} does not start with a number", code))
+}
+
+/// Sum of the complexities of `codes` typed through `robots` directional keypads.
+fn complexity_sum(codes: &[String], robots: usize) -> Result<usize> {
+    let mut cost = PressCost::new();
+    let mut sum = 0;
+    for code in codes {
+        sum += cost.code(code, robots)? * numeric_part(code)?;
+    }
+    Ok(sum)
+}
+
+/// The presses for `code` built move by move through two directional keypads, as the first
+/// version of part 1 did; used to cross-check [`PressCost`].
+fn type_code(code: &str) -> Result<Vec<Move>> {
+    let mut moves = Keypad::numeric().type_keys(code.chars())?;
+    // print_move_vec(&moves);
+
+    let directional = Keypad::directional();
+    for _ in 0..ROBOTS_1 {
+        moves = directional.type_keys(move_string(&moves).chars())?;
+        // print_move_vec(&moves);
+    }
+    Ok(moves)
+}
+
+/// Replays the human's `presses` down the robot chain, through `robots` directional keypads
+/// and then the numeric keypad, and returns the keys pressed on the door, which is the code
+/// if `presses` type it. Fails naming the robot whose arm points at the gap or off its keypad,
+/// or if `presses` holds anything but the keys of the directional keypad.
+///
+/// Every arm only depends on the keys pressed on its own keypad, so the chain is replayed one
+/// keypad at a time.
+pub fn replay(presses: &str, robots: usize) -> Result<String> {
+    let directional = Keypad::directional();
+    let mut typed = presses.to_string();
+    for robot in 1..=robots {
+        typed = directional
+            .replay(&typed)
+            .with_context(|| format!("Robot {} at a directional keypad", robot))?;
+    }
+    Keypad::numeric()
+        .replay(&typed)
+        .with_context(|| format!("Robot {} at the numeric keypad", robots + 1))
+}
+
+/// The moves as the arrows and `A`s on the keypad pressing them.
+pub fn move_string(moves: &[Move]) -> String {
+    moves.iter().map(|m| m.to_char()).collect()
+}
+
+#[allow(dead_code)]
+fn print_move_vec(moves: &[Move]) {
+    println!("{}", move_string(moves));
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Keypad Conquest";
+
+    /// The door codes, one per line.
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input> {
+        Ok(reader.lines().collect::<std::io::Result<_>>()?)
+    }
+
+    fn part1(&self, codes: &Self::Input) -> Result<usize> {
+        complexity_sum(codes, ROBOTS_1)
+    }
+
+    fn part2(&self, codes: &Self::Input) -> Result<usize> {
+        complexity_sum(codes, ROBOTS_2)
+    }
+
+    fn check_examples(&self, part: Part) -> Result<()> {
+        match part {
+            Part::One => {
+                ensure_eq!(126384, self.part1_str(TEST)?);
+                let mut cost = PressCost::new();
+                for (code, presses) in EXAMPLE_PRESSES {
+                    ensure_eq!(code, replay(presses, ROBOTS_1)?);
+                    ensure_eq!(presses.len(), cost.code(code, ROBOTS_1)?);
+                    let typed = type_code(code)?;
+                    ensure_eq!(code, replay(&move_string(&typed), ROBOTS_1)?);
+                    ensure_eq!(typed.len(), presses.len());
+                }
+            }
+            Part::Two => {
+                ensure_eq!(154115708116294, self.part2_str(TEST)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keypad_layout() {
+        let numeric = Keypad::numeric();
+        assert_eq!(Point::new(2, 3), numeric.position('A').unwrap());
+        assert!(numeric.is_gap(Point::new(0, 3)));
+        assert!(numeric.is_gap(Point::new(3, 0)));
+        assert!(numeric.position('B').is_err());
+
+        // From 7 to 0 every way but going down all the way first avoids the gap
+        let ways = |keypad: &Keypad, from, to| -> Vec<String> {
+            let moves = keypad.moves(from, to).unwrap();
+            moves.iter().map(|m| move_string(m)).collect()
+        };
+        assert_eq!(vec![">vvvA", "v>vvA", "vv>vA"], ways(&numeric, '7', '0'));
+        assert_eq!(vec![">^^A", "^^>A", "^>^A"], ways(&numeric, '2', '9'));
+        assert_eq!(vec!["A"], ways(&numeric, '5', '5'));
+
+        // A shape without a puzzle: a ring of keys around the gap
+        let ring = Keypad::new("abc\nd e\nfgA").unwrap();
+        assert!(ring.is_gap(Point::new(1, 1)));
+        assert_eq!(vec!["<<^^A", "^^<<A"], ways(&ring, 'A', 'a'));
+        assert_eq!(vec!["^>>vA", "v>>^A"], ways(&ring, 'd', 'e'));
+        assert_eq!(vec!["<vv>A", ">vv<A"], ways(&ring, 'b', 'g'));
+        assert_eq!(
+            "^>>vA",
+            move_string(&ring.type_keys("de".chars()).unwrap()[4..])
+        );
+        assert_eq!("<<^^A", move_string(&ring.type_keys(['a']).unwrap()));
+        assert!(Keypad::new("ab\nba").is_err());
+    }
+
+    #[test]
+    fn replay_presses() {
+        // The examples from the puzzle for fewer robots
+        assert_eq!("029A", replay("<A^A>^^AvvvA", 0).unwrap());
+        assert_eq!("029A", replay("v<<A>>^A<A>AvA<^AA>A<vAAA>^A", 1).unwrap());
+
+        // Going up first from A on the directional keypad is fine, going left twice is not
+        assert_eq!("^", Keypad::directional().replay("<A").unwrap());
+        let err = replay("<<<A", 1).unwrap_err();
+        assert_eq!("Robot 1 at a directional keypad", err.to_string());
+        assert_eq!(
+            "Arm points at the gap at 0,0 after move 2",
+            err.root_cause().to_string()
+        );
+
+        // From A, the door robot must not go left twice before going up
+        let err = replay("<<^A", 0).unwrap_err();
+        assert!(format!("{:#}", err).contains("gap at 0,3"));
+        assert!(replay("<x", 0).is_err());
+
+        // Nothing is below the door's A key
+        let err = replay("vA", 0).unwrap_err();
+        assert_eq!(
+            "Arm points off the keypad at 2,4 after move 1",
+            err.root_cause().to_string()
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::DynSolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+
+/// All solved days in order.
+pub fn all() -> Vec<Box<dyn DynSolution>> {
+    vec![
+        Box::new(day01::Day01),
+        Box::new(day02::Day02),
+        Box::new(day03::Day03),
+        Box::new(day04::Day04),
+        Box::new(day05::Day05),
+        Box::new(day06::Day06),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08),
+        Box::new(day09::Day09),
+        Box::new(day10::Day10),
+        Box::new(day11::Day11),
+        Box::new(day12::Day12),
+        Box::new(day13::Day13),
+        Box::new(day14::Day14::default()),
+        Box::new(day15::Day15),
+        Box::new(day16::Day16::default()),
+        Box::new(day17::Day17),
+        Box::new(day18::Day18::default()),
+        Box::new(day19::Day19),
+        Box::new(day20::Day20::default()),
+        Box::new(day21::Day21),
+    ]
+}
+
+/// The registered day with the given number.
+pub fn get(day: u8) -> Option<Box<dyn DynSolution>> {
+    all().into_iter().find(|d| d.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Part};
+
+    #[test]
+    fn examples() {
+        for day in all() {
+            for part in Part::BOTH {
+                if day.has_part(part) {
+                    if let Err(err) = day.run_examples(part) {
+                        panic!("Day {:02} part {}: {:#}", day.day(), part, err);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_input() {
+        let cases: [(u8, &str, (usize, usize, &str)); 12] = [
+            (4, "XMAS\nXM\n", (2, 1, "XM")),
+            (5, "47|53\n97|x3\n\n75,47\n", (2, 4, "x3")),
+            (8, "#\n##\n", (1, 1, "#")),
+            (9, "12x3\n", (1, 3, "x")),
+            (10, "0123\n\n4567\n", (3, 1, "4567")),
+            (12, "", (1, 1, "")),
+            (
+                13,
+                "Button A: X+94, Y+34\nButton B: X+22\nPrize: X=8400, Y=5400\n",
+                (2, 15, ""),
+            ),
+            (14, "p=0,4 v=3,-3\np=6,3 v=-1\n", (2, 11, "")),
+            (14, "", (1, 1, "")),
+            (15, "####\n#@X#\n####\n\n<>\n", (2, 3, "X")),
+            (16, "###\n#S.E#\n#?##\n", (3, 2, "?")),
+            (18, "1,2\n99,99\n", (2, 1, "99,99")),
+        ];
+        for (number, input, (line, column, text)) in cases {
+            let day = get(number).unwrap();
+            let err = day.parse_input(&mut input.as_bytes()).err().unwrap();
+            let err = err.downcast_ref::<ParseError>().unwrap();
+            assert_eq!(
+                (Some(number), line, column, text),
+                (err.day, err.line, err.column, err.text.as_str()),
+                "{}",
+                err
+            );
+        }
+
+        let err = get(15).unwrap().parse_input(&mut "#@#\n\n<x\n".as_bytes());
+        assert_eq!(
+            "Day 15, line 3, column 2: Invalid move: \"x\"",
+            err.err().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the text that could not be parsed.
+///
+/// Parsers return it through `anyhow::Result`; the day is filled in by
+/// [`DynSolution::parse_input`](crate::DynSolution::parse_input) when it is not known where the
+/// error is raised, e.g. in [`Grid`](crate::Grid).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    /// Line of the input, starting at 1.
+    pub line: usize,
+    /// Column in characters, starting at 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: impl Into<String>, message: impl Display) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// The whole of `line` could not be parsed.
+    pub fn line(line: usize, text: impl Into<String>, message: impl Display) -> Self {
+        Self::new(line, 1, text, message)
+    }
+
+    /// Sets the day, unless it is known already.
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "Day {:02}, line", day)?,
+            None => write!(f, "Line")?,
+        }
+        write!(
+            f,
+            " {}, column {}: {}: {:?}",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text` found at `line` and `column`, usually a number.
+pub fn parse_at<T>(text: &str, line: usize, column: usize) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|err| ParseError::new(line, column, text, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let err = ParseError::new(3, 4, "X", "Invalid cell");
+        assert_eq!("Line 3, column 4: Invalid cell: \"X\"", err.to_string());
+        let err = err.for_day(15).for_day(16);
+        assert_eq!(
+            "Day 15, line 3, column 4: Invalid cell: \"X\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Ok(-12), parse_at::<isize>("-12", 1, 1));
+        let err = parse_at::<usize>("1x", 2, 5).unwrap_err();
+        assert_eq!((2, 5, "1x"), (err.line, err.column, err.text.as_str()));
+    }
+}
@@ -0,0 +1,287 @@
+use anyhow::{bail, Result};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// A position or offset on a map.
+///
+/// `x` grows to the right (column) and `y` grows downwards (row), so the top left cell of a
+/// puzzle map is `(0, 0)` and [`Direction::Up`] is `(0, -1)`. Coordinates are signed so that
+/// stepping off the map yields a negative position instead of a wrapped `usize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Builds a point from unsigned map coordinates.
+    pub const fn from_usize(x: usize, y: usize) -> Self {
+        Self {
+            x: x as isize,
+            y: y as isize,
+        }
+    }
+
+    /// Unsigned coordinates `(x, y)`, or `None` if either is negative.
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        if self.x < 0 || self.y < 0 {
+            None
+        } else {
+            Some((self.x as usize, self.y as usize))
+        }
+    }
+
+    /// Whether the point lies in `0..width` x `0..height`.
+    pub fn in_bounds(self, bounds: (usize, usize)) -> bool {
+        self.x >= 0 && self.y >= 0 && (self.x as usize) < bounds.0 && (self.y as usize) < bounds.1
+    }
+
+    /// The neighbouring point in `direction`, without any bounds check.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// Moves by `delta`, returning `None` if the result leaves `0..width` x `0..height`.
+    pub fn checked_offset(self, delta: Point, bounds: (usize, usize)) -> Option<Self> {
+        let next = self + delta;
+        if next.in_bounds(bounds) {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// The neighbouring point in `direction`, if it lies in `0..width` x `0..height`.
+    pub fn checked_step(self, direction: Direction, bounds: (usize, usize)) -> Option<Self> {
+        self.checked_offset(direction.delta(), bounds)
+    }
+
+    /// The four orthogonal neighbours in the order of [`Direction::ALL`].
+    pub fn neighbors(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::from_usize(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction) -> Point {
+        self.step(rhs)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four orthogonal directions on a map, see [`Point`] for the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses arrows (`^v<>`) and compass letters (`NESW`).
+    pub fn from_char(c: char) -> Result<Self> {
+        Ok(match c {
+            '^' | 'N' => Direction::Up,
+            '>' | 'E' => Direction::Right,
+            'v' | 'S' => Direction::Down,
+            '<' | 'W' => Direction::Left,
+            _ => bail!("Invalid direction: {}", c),
+        })
+    }
+
+    /// The arrow character of the direction.
+    pub fn as_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    /// Unit step as a point offset.
+    pub fn delta(self) -> Point {
+        let (dx, dy) = self.as_coords();
+        Point::new(dx, dy)
+    }
+
+    /// Unit step as `(dx, dy)`.
+    pub fn as_coords(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    /// Rotates 90° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// Rotates 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// Whether the direction moves along the y axis.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_turn_consistently() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+            assert_eq!(
+                Point::ORIGIN,
+                direction.delta() + direction.opposite().delta()
+            );
+            assert_eq!(
+                direction,
+                Direction::from_char(direction.as_char()).unwrap()
+            );
+        }
+        assert_eq!(Direction::Up, Direction::from_char('N').unwrap());
+        assert_eq!(Direction::Left, Direction::from_char('W').unwrap());
+        assert!(Direction::from_char('x').is_err());
+    }
+
+    #[test]
+    fn checked_steps_stay_in_bounds() {
+        let bounds = (3, 2);
+        let corner = Point::new(0, 0);
+        assert_eq!(None, corner.checked_step(Direction::Up, bounds));
+        assert_eq!(None, corner.checked_step(Direction::Left, bounds));
+        assert_eq!(
+            Some(Point::new(1, 0)),
+            corner.checked_step(Direction::Right, bounds)
+        );
+        assert_eq!(None, Point::new(2, 1).checked_step(Direction::Down, bounds));
+        assert_eq!(
+            None,
+            Point::new(2, 1).checked_step(Direction::Right, bounds)
+        );
+    }
+
+    #[test]
+    fn arithmetic_and_distance() {
+        let a = Point::new(1, 5);
+        let b = Point::new(4, 1);
+        assert_eq!(Point::new(5, 6), a + b);
+        assert_eq!(Point::new(-3, 4), a - b);
+        assert_eq!(Point::new(2, 10), a * 2);
+        assert_eq!(7, a.manhattan_distance(b));
+        assert_eq!(Point::new(1, 4), a + Direction::Up);
+        assert_eq!("1,5", a.to_string());
+    }
+}
@@ -0,0 +1,334 @@
+use crate::error::ParseError;
+use crate::geometry::{Direction, Point};
+use anyhow::{bail, ensure, Result};
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of all eight surrounding cells, clockwise starting above.
+pub const NEIGHBORS_8: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+/// A rectangular map of cells, stored row by row.
+///
+/// Positions are [`Point`]s, `x` being the column and `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        ensure!(
+            cells.len() == width * height,
+            "Expected {} cells for a {}x{} grid, got {}",
+            width * height,
+            width,
+            height,
+            cells.len()
+        );
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Builds a grid of the given size with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a character map, turning every character into a cell with `map_cell`.
+    ///
+    /// Reading stops at the first empty line, so a map followed by a blank line and more
+    /// puzzle input can be read from the same reader. All rows must have the same length.
+    pub fn from_reader<R, F>(reader: R, mut map_cell: F) -> Result<Self>
+    where
+        R: BufRead,
+        F: FnMut(char, Point) -> Result<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                break;
+            }
+            let row_len = line.chars().count();
+            match width {
+                None => width = Some(row_len),
+                Some(w) if w != row_len => bail!(ParseError::line(
+                    height + 1,
+                    line,
+                    format!("Row has length {}, expected {}", row_len, w)
+                )),
+                _ => {}
+            }
+            for (x, c) in line.chars().enumerate() {
+                let cell = map_cell(c, Point::from_usize(x, height))
+                    .map_err(|err| ParseError::new(height + 1, x + 1, c, format!("{:#}", err)))?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+
+    /// Parses a character map from a string, see [`Grid::from_reader`].
+    pub fn parse<F>(input: &str, map_cell: F) -> Result<Self>
+    where
+        F: FnMut(char, Point) -> Result<T>,
+    {
+        Self::from_reader(input.as_bytes(), map_cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Size of the grid as `(width, height)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Whether the position lies inside the grid.
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.in_bounds(self.size())
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Checked access, `None` when the position is outside the grid.
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    /// Checked mutable access, `None` when the position is outside the grid.
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| pos.checked_step(direction, self.size()))
+    }
+
+    /// The up to eight surrounding cells of `pos` that lie inside the grid.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS_8
+            .into_iter()
+            .filter_map(move |delta| pos.checked_offset(delta, self.size()))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| Point::from_usize(i % width, i / width))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// First position in row-major order whose cell matches `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// All positions whose cell matches `predicate`, in row-major order.
+    pub fn find_all<P>(&self, mut predicate: P) -> Vec<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Row `y` as a slice.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} out of range", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Builds a new grid of the same size by mapping every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders the grid line by line with one character per cell.
+    pub fn render<F>(&self, mut to_char: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut to_char));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Grid<char> {
+    /// Reads a plain character map.
+    pub fn from_chars<R: BufRead>(reader: R) -> Result<Self> {
+        Self::from_reader(reader, |c, _| Ok(c))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Unchecked access, panics when the position is outside the grid.
+    fn index(&self, pos: Point) -> &T {
+        match self.index_of(pos) {
+            Some(idx) => &self.cells[idx],
+            None => panic!(
+                "Position {} outside of {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        match self.index_of(pos) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!(
+                "Position {} outside of {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+#.#
+.S.
+#.E
+";
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::from_chars(MAP.as_bytes()).unwrap();
+        assert_eq!((3, 3), grid.size());
+        assert_eq!('S', grid[Point::new(1, 1)]);
+        assert_eq!(Some(&'E'), grid.get(Point::new(2, 2)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!(Some(Point::new(1, 1)), grid.find(|&c| c == 'S'));
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(2, 0), Point::new(0, 2)],
+            grid.find_all(|&c| c == '#')
+        );
+        assert_eq!(MAP, grid.to_string());
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let err = Grid::from_chars("##\n#\n".as_bytes()).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((2, "#"), (err.line, err.text.as_str()));
+
+        let err = Grid::parse("..\n.x\n", |c, _| match c {
+            '.' => Ok(0),
+            _ => bail!("Invalid cell"),
+        })
+        .unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.text.as_str()));
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::filled(3, 2, 0u8);
+        assert_eq!(
+            vec![Point::new(1, 0), Point::new(0, 1)],
+            grid.neighbors4(Point::ORIGIN).collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbors8(Point::new(1, 1)).count());
+        assert_eq!(3, grid.neighbors4(Point::new(1, 1)).count());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(&[3, 4], grid.row(1));
+        assert_eq!(vec![&2, &4], grid.column(1).collect::<Vec<_>>());
+        assert_eq!("12\n34\n", grid.to_string());
+    }
+}
@@ -1,9 +1,71 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod parse;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+
+pub use error::ParseError;
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use solution::{DynSolution, Solution};
+
+use anyhow::{bail, Result};
+use std::fmt::{Display, Formatter};
+
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
 }
 
 // Additional common functions
 
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: &str) -> Result<Self> {
+        match number {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("Invalid part: {}, expected 1 or 2", number),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Like `assert_eq!`, but returns an error instead of panicking.
+#[macro_export]
+macro_rules! ensure_eq {
+    ($expected:expr, $actual:expr $(,)?) => {{
+        let expected = $expected;
+        let actual = $actual;
+        anyhow::ensure!(
+            expected == actual,
+            "Expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,381 @@
+//! Helpers for the recurring shapes of puzzle input.
+//!
+//! Everything works on [`Line`]s, which remember where in the input they come from, so every
+//! mismatch is reported as a [`ParseError`] pointing at the offending text.
+
+use crate::error::{parse_at, ParseError};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A line of the puzzle input, or the part of it starting at `column`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting at 1.
+    pub number: usize,
+    /// Column of the first character of `text`, starting at 1.
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self {
+            number,
+            column: 1,
+            text,
+        }
+    }
+
+    /// An error for the text starting `offset` bytes into this line.
+    pub fn error_at(&self, offset: usize, text: &str, message: impl Display) -> ParseError {
+        let column = self.column + self.text[..offset].chars().count();
+        ParseError::new(self.number, column, text, message)
+    }
+
+    /// An error for the whole of this line.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        self.error_at(0, self.text, message)
+    }
+
+    /// The `len` bytes of the line starting at byte `offset`.
+    fn slice(&self, offset: usize, len: usize) -> Line<'a> {
+        Line {
+            number: self.number,
+            column: self.column + self.text[..offset].chars().count(),
+            text: &self.text[offset..offset + len],
+        }
+    }
+
+    /// The line without leading and trailing whitespace.
+    pub fn trim(&self) -> Line<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.slice(start, self.text.trim().len())
+    }
+
+    /// The part of the line before `marker`, or all of it, e.g. to drop a comment.
+    pub fn before(&self, marker: char) -> Line<'a> {
+        self.slice(0, self.text.find(marker).unwrap_or(self.text.len()))
+    }
+
+    /// Parses the whole line, usually as a number.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        parse_at(self.text, self.number, self.column)
+    }
+
+    /// The rest of the line after `prefix`, which must be there.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Line<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.slice(prefix.len(), rest.len())),
+            None => Err(self.error(format!("Expected {:?}", prefix))),
+        }
+    }
+
+    /// The parts before and after the first `separator`, which must be there.
+    pub fn split_once(&self, separator: &str) -> Result<(Line<'a>, Line<'a>), ParseError> {
+        match self.text.find(separator) {
+            Some(idx) => {
+                let after = idx + separator.len();
+                Ok((
+                    self.slice(0, idx),
+                    self.slice(after, self.text.len() - after),
+                ))
+            }
+            None => Err(self.error(format!("Expected {:?}", separator))),
+        }
+    }
+
+    /// All signed integers in the line, ignoring whatever is around them.
+    ///
+    /// `"p=0,4 v=3,-3"` gives `[0, 4, 3, -3]`; a `-` counts as a sign only directly in front of
+    /// a digit.
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut numbers = Vec::new();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let negative = bytes[idx] == b'-' && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[idx].is_ascii_digit() {
+                idx += 1;
+                continue;
+            }
+            let start = idx;
+            idx += 1;
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                idx += 1;
+            }
+            numbers.push(self.slice(start, idx - start).parse()?);
+        }
+        Ok(numbers)
+    }
+
+    /// Values separated by `separator`, with spaces around them ignored.
+    ///
+    /// With `' '` as separator, runs of spaces separate the values, so `"1  2"` is two values.
+    pub fn list<T>(&self, separator: char) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut values = Vec::new();
+        let mut offset = 0;
+        for part in self.text.split(separator) {
+            let trimmed = part.trim_start();
+            let start = offset + part.len() - trimmed.len();
+            let trimmed = trimmed.trim_end();
+            offset += part.len() + separator.len_utf8();
+            if trimmed.is_empty() && separator == ' ' {
+                continue;
+            }
+            values.push(self.slice(start, trimmed.len()).parse()?);
+        }
+        Ok(values)
+    }
+
+    /// Parses a line of a fixed shape, where every `{}` in `pattern` stands for a signed
+    /// integer and the rest has to match exactly.
+    ///
+    /// `"Button A: X+94, Y+34"` with the pattern `"Button A: X+{}, Y+{}"` gives `[94, 34]`.
+    pub fn record<T, const N: usize>(&self, pattern: &str) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        // Points at the first character that differs from `literal`
+        let expect = |offset: usize, literal: &str| {
+            let rest = &self.text[offset..];
+            if rest.starts_with(literal) {
+                return Ok(offset + literal.len());
+            }
+            let same: usize = rest
+                .chars()
+                .zip(literal.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a.len_utf8())
+                .sum();
+            let at = offset + same;
+            Err(self.error_at(at, &self.text[at..], format!("Expected {:?}", pattern)))
+        };
+
+        let mut literals = pattern.split("{}");
+        let mut offset = expect(0, literals.next().unwrap_or_default())?;
+        let mut values = Vec::with_capacity(N);
+        for literal in literals {
+            let rest = &self.text.as_bytes()[offset..];
+            let sign = usize::from(matches!(rest.first(), Some(b'-' | b'+')));
+            let digits = rest[sign..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            if digits == 0 {
+                let text = &self.text[offset..];
+                return Err(self.error_at(offset, text, format!("Expected {:?}", pattern)));
+            }
+            values.push(self.slice(offset, sign + digits).parse()?);
+            offset = expect(offset + sign + digits, literal)?;
+        }
+        if offset != self.text.len() {
+            let text = &self.text[offset..];
+            return Err(self.error_at(offset, text, format!("Expected {:?}", pattern)));
+        }
+
+        let found = values.len();
+        values.try_into().map_err(|_| {
+            self.error(format!(
+                "Pattern {:?} has {} values, expected {}",
+                pattern, found, N
+            ))
+        })
+    }
+}
+
+/// The lines of `input`, without trailing `\r` of CRLF line endings.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| Line::new(idx + 1, text.trim_end_matches('\r')))
+}
+
+/// A block of lines, separated from the next one by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub lines: Vec<Line<'a>>,
+}
+
+impl Section<'_> {
+    /// The lines of the section joined with `\n`, for parsers that want the whole block.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for line in &self.lines {
+            text.push_str(line.text);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Number of the first line of the section in the input.
+    pub fn first_line(&self) -> usize {
+        self.lines.first().map_or(1, |line| line.number)
+    }
+}
+
+/// Splits `input` into the blocks separated by blank lines.
+///
+/// Any number of blank lines, also at the start and end, and lines containing only
+/// whitespace count as one separator, so no section is ever empty.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current = Vec::new();
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                sections.push(Section {
+                    lines: std::mem::take(&mut current),
+                });
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        sections.push(Section { lines: current });
+    }
+    sections
+}
+
+/// Splits `input` into exactly `N` sections, see [`sections`].
+pub fn n_sections<const N: usize>(input: &str) -> Result<[Section<'_>; N], ParseError> {
+    let sections = sections(input);
+    let found = sections.len();
+    sections.try_into().map_err(|sections: Vec<Section>| {
+        let line = match sections.get(N) {
+            Some(extra) => extra.lines[0],
+            None => Line::new(lines(input).count() + 1, ""),
+        };
+        line.error(format!(
+            "Expected {} sections separated by blank lines, found {}",
+            N, found
+        ))
+    })
+}
+
+/// A rectangular map of characters, one row per line and without blank lines in between.
+///
+/// Every character must satisfy `valid`; `expected` describes the valid ones for the error.
+pub fn char_rows(
+    input: &str,
+    expected: &str,
+    valid: impl Fn(char) -> bool,
+) -> Result<Vec<Vec<char>>, ParseError> {
+    let [map] = n_sections::<1>(input)?;
+    let width = map.lines[0].text.chars().count();
+    let mut rows = Vec::new();
+    for line in &map.lines {
+        let row: Vec<char> = line.text.chars().collect();
+        if row.len() != width {
+            return Err(line.error(format!("Row has length {}, expected {}", row.len(), width)));
+        }
+        if let Some((offset, c)) = line.text.char_indices().find(|&(_, c)| !valid(c)) {
+            let message = format!("Invalid character, expected {}", expected);
+            return Err(line.error_at(offset, &c.to_string(), message));
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_tolerate_crlf_and_blank_lines() {
+        let input = "\r\n#.#\r\n.#.\r\n\r\n  \r\n\r\n<>^\r\n\r\n";
+        let [map, moves] = n_sections::<2>(input).unwrap();
+        assert_eq!("#.#\n.#.\n", map.text());
+        assert_eq!(2, map.first_line());
+        assert_eq!(7, moves.first_line());
+        assert_eq!("<>^", moves.lines[0].text);
+
+        let err = n_sections::<2>("a\n\nb\n\nc\n").unwrap_err();
+        assert_eq!((5, "c"), (err.line, err.text.as_str()));
+        let err = n_sections::<2>("a\nb\n").unwrap_err();
+        assert_eq!(3, err.line);
+    }
+
+    #[test]
+    fn ints() {
+        let line = Line::new(1, "p=0,4 v=3,-3 w=-x 12");
+        assert_eq!(Ok(vec![0, 4, 3, -3, 12]), line.ints::<isize>());
+        let err = Line::new(4, "a=1 b=-300").ints::<i8>().unwrap_err();
+        assert_eq!((4, 7, "-300"), (err.line, err.column, err.text.as_str()));
+    }
+
+    #[test]
+    fn lists() {
+        let line = Line::new(1, "190: 10  19");
+        let (result, values) = line.split_once(": ").unwrap();
+        assert_eq!(Ok(190), result.parse::<usize>());
+        assert_eq!(Ok(vec![10, 19]), values.list::<usize>(' '));
+
+        let line = Line::new(2, "r, wr, b");
+        assert_eq!(
+            Ok(vec!["r".to_string(), "wr".to_string(), "b".to_string()]),
+            line.list::<String>(',')
+        );
+
+        let program = Line::new(3, "Program: 0,1,x")
+            .strip_prefix("Program: ")
+            .unwrap();
+        let err = program.list::<u8>(',').unwrap_err();
+        assert_eq!((3, 14, "x"), (err.line, err.column, err.text.as_str()));
+        assert!(Line::new(3, "Programm: 0")
+            .strip_prefix("Program: ")
+            .is_err());
+    }
+
+    #[test]
+    fn records() {
+        let line = Line::new(1, "Button A: X+94, Y+34");
+        assert_eq!(
+            Ok([94, 34]),
+            line.record::<usize, 2>("Button A: X+{}, Y+{}")
+        );
+        assert_eq!(
+            Ok([-5]),
+            Line::new(1, "Register A: -5").record::<i64, 1>("Register A: {}")
+        );
+
+        let err = line.record::<usize, 2>("Button B: X+{}, Y+{}").unwrap_err();
+        assert_eq!((1, 8), (err.line, err.column));
+        let err = line.record::<usize, 2>("Button A: X+{}, Y={}").unwrap_err();
+        assert_eq!((18, "+34"), (err.column, err.text.as_str()));
+        assert!(line.record::<usize, 2>("Button A: X+{}").is_err());
+        assert!(line.record::<usize, 3>("Button A: X+{}, Y+{}").is_err());
+    }
+
+    #[test]
+    fn char_maps() {
+        let digits = |input| char_rows(input, "a digit", |c| c.is_ascii_digit());
+        assert_eq!(
+            Ok(vec![vec!['1', '2'], vec!['3', '4']]),
+            digits("12\r\n34\r\n")
+        );
+
+        let err = digits("12\n3\n").unwrap_err();
+        assert_eq!((2, 1, "3"), (err.line, err.column, err.text.as_str()));
+        let err = digits("12\n3x\n").unwrap_err();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.text.as_str()));
+        let err = digits("12\n\n34\n").unwrap_err();
+        assert_eq!((3, "34"), (err.line, err.text.as_str()));
+        assert!(digits("").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use anyhow::{bail, ensure, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The day template, with `NN` standing for the day number.
+const TEMPLATE: &str = include_str!("days/NN.rs");
+
+/// Fills the day number into the template.
+pub fn render_template(day: u8) -> String {
+    TEMPLATE
+        .replace("const DAY: u8 = NN;", &format!("const DAY: u8 = {};", day))
+        .replace("NN", &format!("{:02}", day))
+}
+
+/// Adds the module declaration and the `all()` entry for `day` to the source of `days/mod.rs`,
+/// keeping both lists ordered by day.
+pub fn register_day(mod_rs: &str, day: u8) -> Result<String> {
+    let module = format!("day{:02}", day);
+    let mod_line = format!("pub mod {};", module);
+    let entry_line = format!("        Box::new({}::Day{:02}),", module, day);
+
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+    ensure!(
+        !lines.iter().any(|line| line.trim() == mod_line),
+        "Day {:02} is already registered",
+        day
+    );
+
+    insert_sorted(&mut lines, "pub mod day", mod_line)?;
+    insert_sorted(&mut lines, "        Box::new(day", entry_line)?;
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Ok(out)
+}
+
+/// Inserts `new_line` into the block of lines starting with `prefix`, after the last one that
+/// sorts before it.
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, new_line: String) -> Result<()> {
+    let block: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let first = *block
+        .first()
+        .with_context(|| format!("No lines starting with `{}` found", prefix.trim()))?;
+
+    let position = block
+        .iter()
+        .rev()
+        .find(|&&i| lines[i] < new_line)
+        .map_or(first, |&i| i + 1);
+    lines.insert(position, new_line);
+    Ok(())
+}
+
+/// Creates the module, an empty input file and an empty examples file for `day` below `root`
+/// and registers the module. Returns the created files.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    ensure!(
+        (1..=25).contains(&day),
+        "Invalid day: {}, expected 1 to 25",
+        day
+    );
+
+    let module_path = root.join(format!("src/days/day{:02}.rs", day));
+    if module_path.exists() {
+        bail!("{} already exists", module_path.display());
+    }
+    let mod_rs_path = root.join("src/days/mod.rs");
+    let mod_rs = fs::read_to_string(&mod_rs_path)
+        .with_context(|| format!("Cannot read {}", mod_rs_path.display()))?;
+    let registered = register_day(&mod_rs, day)?;
+
+    let mut created = Vec::new();
+    fs::write(&module_path, render_template(day))?;
+    created.push(module_path);
+
+    // Input and examples may already have been downloaded, so those are kept as they are
+    for dir in ["input", "examples"] {
+        let path = root.join(dir).join(format!("{:02}.txt", day));
+        if !path.exists() {
+            fs::create_dir_all(root.join(dir))?;
+            fs::write(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    fs::write(&mod_rs_path, registered)?;
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "\
+pub mod day01;
+pub mod day03;
+
+pub fn all() -> Vec<Box<dyn DynSolution>> {
+    vec![
+        Box::new(day01::Day01),
+        Box::new(day03::Day03),
+    ]
+}
+";
+
+    #[test]
+    fn registers_in_order() {
+        let registered = register_day(MOD_RS, 2).unwrap();
+        assert_eq!(
+            registered,
+            "\
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub fn all() -> Vec<Box<dyn DynSolution>> {
+    vec![
+        Box::new(day01::Day01),
+        Box::new(day02::Day02),
+        Box::new(day03::Day03),
+    ]
+}
+"
+        );
+        assert!(register_day(&registered, 2).is_err());
+
+        let appended = register_day(MOD_RS, 22).unwrap();
+        assert!(appended.contains("pub mod day03;\npub mod day22;\n"));
+        assert!(appended.contains("Box::new(day03::Day03),\n        Box::new(day22::Day22),\n"));
+    }
+
+    #[test]
+    fn renders_template() {
+        let rendered = render_template(7);
+        assert!(rendered.contains("pub struct Day07;"));
+        assert!(rendered.contains("const DAY: u8 = 7;"));
+        assert!(rendered.contains("include_str!(\"../../examples/07.txt\")"));
+        assert!(!rendered.contains("NN"));
+        // Unsolved parts must fail rather than return an answer `verify --record` would keep
+        assert_eq!(2, rendered.matches("bail!(\"Not solved yet\")").count());
+        assert!(rendered.contains("fn has_part2(&self) -> bool {\n        false"));
+    }
+}
@@ -0,0 +1,367 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Outcome of a [`bfs`], [`dijkstra`] or [`astar`] run.
+///
+/// Besides the distance of every reached state it keeps *all* predecessors over which a state
+/// is reached at its minimal cost, so ties between optimal paths are not lost. Start states
+/// never get predecessors, and 0-cost steps never close a cycle of predecessors, so following
+/// them always ends at a start.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    starts: HashSet<S>,
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    fn new() -> Self {
+        Self {
+            starts: HashSet::new(),
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Minimal cost of every state reached before the search stopped.
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// All states from which `state` is reached at its minimal cost.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Goal states reached at the minimal goal cost, in the order they were settled.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Cost of the cheapest goal, `None` if no goal was reached.
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// One optimal path from a start state to `target`, both ends included.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(previous) = self.predecessors(current).first() {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// One optimal path to the first goal.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// Every optimal path from a start state to `target`.
+    ///
+    /// The number of paths can grow exponentially, prefer [`SearchResult::on_optimal_paths`]
+    /// when only the visited states are of interest.
+    pub fn all_paths_to(&self, target: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(target) {
+            return Vec::new();
+        }
+        let predecessors = self.predecessors(target);
+        if predecessors.is_empty() {
+            return vec![vec![target.clone()]];
+        }
+        let mut paths = Vec::new();
+        for previous in predecessors {
+            for mut path in self.all_paths_to(previous) {
+                path.push(target.clone());
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// All states lying on at least one optimal path to any of `targets`.
+    pub fn on_optimal_paths<'a, I>(&'a self, targets: I) -> HashSet<S>
+    where
+        I: IntoIterator<Item = &'a S>,
+    {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&S> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(*target))
+            .collect();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors(state));
+            }
+        }
+        seen
+    }
+
+    /// Adds a start state at cost 0, returns whether it was new.
+    fn add_start(&mut self, start: S) -> bool {
+        self.distances.insert(start.clone(), 0);
+        self.starts.insert(start)
+    }
+
+    /// Whether `ancestor` is `state` or lies on one of its chains of predecessors.
+    fn leads_to(&self, ancestor: &S, state: &S) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = vec![state];
+        while let Some(current) = stack.pop() {
+            if current == ancestor {
+                return true;
+            }
+            if seen.insert(current) {
+                stack.extend(self.predecessors(current));
+            }
+        }
+        false
+    }
+
+    /// Records that `next` can be reached from `state` at cost `cost`, returns whether this
+    /// improved the best known cost of `next`.
+    fn relax(&mut self, state: &S, next: S, cost: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(&known) if known < cost => false,
+            Some(&known) if known == cost => {
+                // Over a 0-cost step `next` may already be a predecessor of `state`
+                let zero_step = self.distances.get(state) == Some(&cost);
+                if self.starts.contains(&next) || zero_step && self.leads_to(&next, state) {
+                    return false;
+                }
+                let predecessors = self.predecessors.entry(next).or_default();
+                if !predecessors.contains(state) {
+                    predecessors.push(state.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![state.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+///
+/// Stops after the layer in which the first goal was found; pass `|_| false` as `is_goal` to
+/// explore everything reachable.
+pub fn bfs<S, I, N, J, G>(starts: I, mut successors: N, mut is_goal: G) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> J,
+    J: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if result.add_start(start.clone()) {
+            queue.push_back((start, 0));
+        }
+    }
+
+    let mut goal_cost = None;
+    while let Some((state, cost)) = queue.pop_front() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            result.goals.push(state);
+            continue;
+        }
+        for next in successors(&state) {
+            if result.relax(&state, next.clone(), cost + 1) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    result
+}
+
+/// Dijkstra's algorithm over non-negative step costs.
+///
+/// Steps may cost 0, even in cycles: states reached at the same cost over them share it, but
+/// the predecessors stay free of cycles.
+///
+/// `successors` yields `(state, cost)` pairs. The search stops once every goal with the minimal
+/// cost has been settled; pass `|_| false` as `is_goal` to compute the full distance map.
+pub fn dijkstra<S, I, N, J, G>(starts: I, successors: N, is_goal: G) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> J,
+    J: IntoIterator<Item = (S, usize)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a goal and should be
+/// consistent, otherwise the reported costs are not guaranteed to be minimal.
+pub fn astar<S, I, N, J, H, G>(
+    starts: I,
+    mut successors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> J,
+    J: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new();
+    // The heap stores indices into `queued`, so states need neither `Ord` nor cheap clones.
+    let mut queued = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if result.add_start(start.clone()) {
+            heap.push(Reverse((heuristic(&start), 0, queued.len())));
+            queued.push(start);
+        }
+    }
+
+    let mut settled = HashSet::new();
+    let mut goal_cost = None;
+    while let Some(Reverse((estimate, cost, idx))) = heap.pop() {
+        if goal_cost.is_some_and(|goal_cost| estimate > goal_cost) {
+            break;
+        }
+        let state = queued[idx].clone();
+        if result.distances[&state] < cost || !settled.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            result.goals.push(state);
+            continue;
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if result.relax(&state, next.clone(), next_cost) {
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    queued.len(),
+                )));
+                queued.push(next);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "\
+S...#
+.##.#
+....E
+";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = Grid::from_chars(MAZE.as_bytes()).unwrap();
+        let start = grid.find(|&c| c == 'S').unwrap();
+        let end = grid.find(|&c| c == 'E').unwrap();
+        (grid, start, end)
+    }
+
+    #[test]
+    fn bfs_finds_all_shortest_paths() {
+        let (grid, start, end) = maze();
+        let result = bfs(
+            [start],
+            |&pos| grid.neighbors4(pos).filter(|&next| grid[next] != '#'),
+            |&pos| pos == end,
+        );
+        assert_eq!(Some(6), result.goal_cost());
+        assert_eq!(7, result.goal_path().unwrap().len());
+        assert_eq!(2, result.all_paths_to(&end).len());
+        assert_eq!(11, result.on_optimal_paths(result.goals()).len());
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let (grid, start, end) = maze();
+        // Moving right is cheap, every other step is expensive.
+        let successors = |&pos: &Point| {
+            grid.neighbors4(pos)
+                .filter(|&next| grid[next] != '#')
+                .map(move |next| (next, if next.x > pos.x { 1 } else { 5 }))
+                .collect::<Vec<_>>()
+        };
+        let plain = dijkstra([start], successors, |&pos| pos == end);
+        let guided = astar(
+            [start],
+            successors,
+            |&pos| pos.manhattan_distance(end),
+            |&pos| pos == end,
+        );
+        assert_eq!(Some(14), plain.goal_cost());
+        assert_eq!(plain.goal_cost(), guided.goal_cost());
+        let mut plain_paths = plain.all_paths_to(&end);
+        let mut guided_paths = guided.all_paths_to(&end);
+        plain_paths.sort();
+        guided_paths.sort();
+        assert_eq!(2, plain_paths.len());
+        assert_eq!(plain_paths, guided_paths);
+    }
+
+    #[test]
+    fn exhaustive_search_without_goal() {
+        let (grid, start, end) = maze();
+        let result = bfs(
+            [start],
+            |&pos| grid.neighbors4(pos).filter(|&next| grid[next] != '#'),
+            |_| false,
+        );
+        assert_eq!(None, result.goal_cost());
+        assert_eq!(Some(6), result.distance(&end));
+        assert_eq!(11, result.distances().len());
+        assert!(result.path_to(&Point::new(4, 0)).is_none());
+    }
+
+    #[test]
+    fn zero_cost_cycles() {
+        // 0 and 1 are joined both ways at no cost, 1 has a 0-cost loop and leads on to 2
+        let successors = |&state: &u8| match state {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (1, 0), (2, 1)],
+            _ => vec![],
+        };
+        let result = dijkstra([0], successors, |&state| state == 2);
+        assert_eq!(Some(1), result.goal_cost());
+        assert!(result.predecessors(&0).is_empty());
+        assert_eq!(&[0], result.predecessors(&1));
+        assert_eq!(Some(vec![0, 1, 2]), result.goal_path());
+        assert_eq!(vec![vec![0, 1, 2]], result.all_paths_to(&2));
+
+        // Starts reached from each other get no predecessors either
+        let result = dijkstra([0, 1], successors, |_| false);
+        assert!(result.predecessors(&0).is_empty());
+        assert!(result.predecessors(&1).is_empty());
+        assert_eq!(vec![vec![1, 2]], result.all_paths_to(&2));
+    }
+}
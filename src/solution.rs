@@ -0,0 +1,138 @@
+use crate::{ParseError, Part};
+use anyhow::{bail, Context, Result};
+use std::any::Any;
+use std::fmt::Display;
+use std::io::BufRead;
+
+/// A day's puzzle: parses the input once and solves both parts from the parsed form.
+///
+/// Days that need extra parameters (grid sizes, thresholds, ...) carry them as fields and
+/// use `Default` for the values of the real puzzle input. Parameters worth changing from the
+/// command line are accepted by [`Solution::set_option`].
+pub trait Solution {
+    /// Day of the puzzle, 1 to 25.
+    const DAY: u8;
+    /// Title of the puzzle as shown on the Advent of Code website.
+    const TITLE: &'static str;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Whether `part2` has been solved yet.
+    fn has_part2(&self) -> bool {
+        true
+    }
+
+    /// Checks the worked examples from the puzzle description.
+    fn check_examples(&self, part: Part) -> Result<()>;
+
+    /// Sets the parameter `name` from its textual `value`, as given by `--set name=value`.
+    fn set_option(&mut self, name: &str, _value: &str) -> Result<()> {
+        bail!("Day {:02} has no option {}", Self::DAY, name)
+    }
+
+    /// Parses `input` and solves part 1, mostly for the examples.
+    fn part1_str(&self, input: &str) -> Result<Self::Answer1> {
+        self.part1(&self.parse(&mut input.as_bytes())?)
+    }
+
+    /// Parses `input` and solves part 2, mostly for the examples.
+    fn part2_str(&self, input: &str) -> Result<Self::Answer2> {
+        self.part2(&self.parse(&mut input.as_bytes())?)
+    }
+}
+
+/// Object-safe view of a [`Solution`], so the runner can hold every day in one list.
+///
+/// The parsed input is passed around as `dyn Any`, which keeps parsing and solving
+/// separately callable (and timeable).
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn has_part(&self, part: Part) -> bool;
+
+    fn run_examples(&self, part: Part) -> Result<()>;
+
+    fn set_option(&mut self, name: &str, value: &str) -> Result<()>;
+
+    /// Parses the input, adding the day to a [`ParseError`] that does not know it.
+    fn parse_input(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>>;
+
+    /// Solves `part` on an input returned by `parse_input` of the same day.
+    fn solve_parsed(&self, part: Part, input: &dyn Any) -> Result<String>;
+
+    fn solve(&self, part: Part, reader: &mut dyn BufRead) -> Result<String> {
+        let input = self.parse_input(reader)?;
+        self.solve_parsed(part, input.as_ref())
+    }
+
+    /// Path of the puzzle input, relative to the repository root.
+    fn input_path(&self) -> String {
+        format!("input/{:02}.txt", self.day())
+    }
+
+    /// Path of the accepted answers for the puzzle input, relative to the repository root.
+    fn answers_path(&self) -> String {
+        format!("answers/{:02}.toml", self.day())
+    }
+}
+
+impl<S: Solution> DynSolution for S
+where
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn has_part(&self, part: Part) -> bool {
+        match part {
+            Part::One => true,
+            Part::Two => self.has_part2(),
+        }
+    }
+
+    fn run_examples(&self, part: Part) -> Result<()> {
+        self.check_examples(part)
+    }
+
+    fn set_option(&mut self, name: &str, value: &str) -> Result<()> {
+        Solution::set_option(self, name, value)
+    }
+
+    fn parse_input(&self, reader: &mut dyn BufRead) -> Result<Box<dyn Any>> {
+        match self.parse(reader) {
+            Ok(input) => Ok(Box::new(input)),
+            Err(mut err) => {
+                if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+                    parse_error.day.get_or_insert(S::DAY);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    fn solve_parsed(&self, part: Part, input: &dyn Any) -> Result<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .context("Input was not parsed by this day")?;
+        match part {
+            Part::One => Ok(self.part1(input)?.to_string()),
+            Part::Two if self.has_part2() => Ok(self.part2(input)?.to_string()),
+            Part::Two => bail!("Part 2 of day {:02} is not solved yet", S::DAY),
+        }
+    }
+}